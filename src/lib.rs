@@ -1,8 +1,22 @@
-use std::fmt;
-use std::time::{Duration, SystemTime, SystemTimeError};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-/// Enum with the seven days of the week.
-#[derive(Debug, Clone, Copy)]
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, SystemTimeError};
+
+/// Enum with the seven days of the week. The ordering follows the declaration
+/// order, from Sunday through to Saturday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Day {
     Sunday,
     Monday,
@@ -13,6 +27,42 @@ pub enum Day {
     Saturday,
 }
 
+impl Day {
+    /// Converts the day to a stable integer value, suitable for FFI. Sunday is 0,
+    /// Monday is 1, and so on through Saturday which is 6.
+    pub fn as_u8(self) -> u8 {
+        index_from_day(self) as u8
+    }
+
+    /// The inverse of `as_u8`. Values outside the 0-6 range are converted to
+    /// `None`.
+    pub fn from_u8(value: u8) -> Option<Day> {
+        day_from_index(u64::from(value))
+    }
+
+    /// Returns the day of the week following this one, wrapping around from
+    /// Saturday to Sunday.
+    pub fn next(self) -> Day {
+        day_from_index((index_from_day(self) + 1) % 7).expect("Day was in range")
+    }
+
+    /// Returns the day of the week before this one, wrapping around from Sunday
+    /// to Saturday.
+    pub fn previous(self) -> Day {
+        day_from_index((index_from_day(self) + 6) % 7).expect("Day was in range")
+    }
+
+    /// Returns true for Saturday and Sunday.
+    pub fn is_weekend(self) -> bool {
+        matches!(self, Day::Saturday | Day::Sunday)
+    }
+
+    /// Returns true for Monday through Friday.
+    pub fn is_weekday(self) -> bool {
+        !self.is_weekend()
+    }
+}
+
 /// Maps the `Day` enum to a string representation, e.g. "Monday".
 pub fn day_string(day: Day) -> &'static str {
     match day {
@@ -31,14 +81,119 @@ pub fn day_abbrev_string(day: Day) -> &'static str {
     &day_string(day)[0..3]
 }
 
+/// A language for the names produced by `day_string_locale` and
+/// `month_string_locale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+/// Maps the `Day` enum to its name in the given locale, e.g. "lundi" for Monday
+/// in French. Names are capitalized as they would be mid-sentence in that
+/// language. The `English` locale gives the same result as `day_string`. There is
+/// no localized version of `day_abbrev_string`; abbreviations are English-only
+/// for now.
+pub fn day_string_locale(day: Day, locale: Locale) -> &'static str {
+    let names = match locale {
+        Locale::English => return day_string(day),
+        Locale::French => [
+            "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+        ],
+        Locale::German => [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        Locale::Spanish => [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ],
+    };
+    names[index_from_day(day) as usize]
+}
+
+/// The inverse of `day_string` and `day_abbrev_string`. Accepts either the full
+/// name or the three-letter abbreviation, ignoring ASCII case, e.g. "Monday",
+/// "mon" or "MON". Anything else is converted to `None`.
+pub fn day_from_string(s: &str) -> Option<Day> {
+    (0..7).filter_map(day_from_index).find(|&day| {
+        s.eq_ignore_ascii_case(day_string(day)) || s.eq_ignore_ascii_case(day_abbrev_string(day))
+    })
+}
+
+/// Returns the names of the seven days of the week in order, starting from
+/// `first`. This is useful for calendar headers, where the week may start on
+/// Sunday or Monday depending on locale. If `abbreviated` is true the names are
+/// as given by `day_abbrev_string`, otherwise as by `day_string`.
+pub fn weekday_headers(first: Day, abbreviated: bool) -> Vec<&'static str> {
+    let mut day = first;
+    let mut headers = Vec::with_capacity(7);
+    for _ in 0..7 {
+        headers.push(if abbreviated {
+            day_abbrev_string(day)
+        } else {
+            day_string(day)
+        });
+        day = day.next();
+    }
+    headers
+}
+
+/// Converts a `Day` enum to an integer in the range 0-6, where Sunday is 0 and
+/// Saturday is 6.
+pub fn index_from_day(day: Day) -> u64 {
+    match day {
+        Day::Sunday => 0,
+        Day::Monday => 1,
+        Day::Tuesday => 2,
+        Day::Wednesday => 3,
+        Day::Thursday => 4,
+        Day::Friday => 5,
+        Day::Saturday => 6,
+    }
+}
+
+/// Converts an integer in the range 0-6 into the corresponding `Day` enum, where
+/// 0 is Sunday. Values outside the 0-6 range are converted to `None`.
+pub fn day_from_index(index: u64) -> Option<Day> {
+    match index {
+        0 => Some(Day::Sunday),
+        1 => Some(Day::Monday),
+        2 => Some(Day::Tuesday),
+        3 => Some(Day::Wednesday),
+        4 => Some(Day::Thursday),
+        5 => Some(Day::Friday),
+        6 => Some(Day::Saturday),
+        _ => None,
+    }
+}
+
+// Returns the ISO 8601 weekday number, which is 1 for Monday through 7 for Sunday.
+fn iso_weekday(day: Day) -> u64 {
+    (index_from_day(day) + 6) % 7 + 1
+}
+
 impl fmt::Display for Day {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", day_string(*self))
     }
 }
 
-/// Enum with the months of the year.
-#[derive(Debug, Clone, Copy)]
+/// Enum with the months of the year. The ordering follows the declaration
+/// order, from January through to December.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Month {
     January,
     February,
@@ -54,6 +209,33 @@ pub enum Month {
     December,
 }
 
+impl Month {
+    /// Converts the month to a stable integer value, suitable for FFI. January is
+    /// 1, February is 2, and so on through December which is 12. This matches
+    /// `index_from_month`.
+    pub fn as_u8(self) -> u8 {
+        index_from_month(self) as u8
+    }
+
+    /// The inverse of `as_u8`. Values outside the 1-12 range are converted to
+    /// `None`.
+    pub fn from_u8(value: u8) -> Option<Month> {
+        month_from_index(u64::from(value))
+    }
+
+    /// Returns the month following this one, wrapping around from December to
+    /// January.
+    pub fn next(self) -> Month {
+        month_from_index(index_from_month(self) % 12 + 1).expect("Month was in range")
+    }
+
+    /// Returns the month before this one, wrapping around from January to
+    /// December.
+    pub fn previous(self) -> Month {
+        month_from_index((index_from_month(self) + 10) % 12 + 1).expect("Month was in range")
+    }
+}
+
 /// Maps the `Month` enum to a string representation, e.g. "January".
 pub fn month_string(month: Month) -> &'static str {
     match month {
@@ -77,30 +259,140 @@ pub fn month_abbrev_string(month: Month) -> &'static str {
     &month_string(month)[0..3]
 }
 
+/// Maps the `Month` enum to its name in the given locale, e.g. "März" for March
+/// in German. Names are capitalized as they would be mid-sentence in that
+/// language. The `English` locale gives the same result as `month_string`. As
+/// with `day_string_locale`, abbreviations are English-only for now.
+pub fn month_string_locale(month: Month, locale: Locale) -> &'static str {
+    let names = match locale {
+        Locale::English => return month_string(month),
+        Locale::French => [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        Locale::German => [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        Locale::Spanish => [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+    };
+    names[index_from_month(month) as usize - 1]
+}
+
+/// The inverse of `month_string` and `month_abbrev_string`. Accepts either the
+/// full name or the three-letter abbreviation, ignoring ASCII case, e.g.
+/// "January", "jan" or "JAN". Anything else is converted to `None`.
+pub fn month_from_string(s: &str) -> Option<Month> {
+    (1..=12).filter_map(month_from_index).find(|&month| {
+        s.eq_ignore_ascii_case(month_string(month))
+            || s.eq_ignore_ascii_case(month_abbrev_string(month))
+    })
+}
+
 impl fmt::Display for Month {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", month_string(*self))
     }
 }
 
+/// The error type returned when converting an integer outside the 1-12 range
+/// into a `Month`. Contains the offending value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMonthIndex(pub u64);
+
+impl fmt::Display for InvalidMonthIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a valid month index", self.0)
+    }
+}
+
+impl Error for InvalidMonthIndex {}
+
+impl TryFrom<u64> for Month {
+    type Error = InvalidMonthIndex;
+
+    /// Equivalent to `month_from_index`, but usable with `?` and generic code.
+    fn try_from(index: u64) -> Result<Self, Self::Error> {
+        month_from_index(index).ok_or(InvalidMonthIndex(index))
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = InvalidMonthIndex;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Month::try_from(u64::from(index))
+    }
+}
+
+impl From<Month> for u64 {
+    /// Equivalent to `index_from_month`.
+    fn from(month: Month) -> Self {
+        index_from_month(month)
+    }
+}
+
+/// Takes in a year (e.g. 2020) and returns true if it is a leap year under the
+/// Gregorian rules, i.e. if it is divisible by 4, except for years divisible by
+/// 100 but not by 400.
+pub const fn is_leap_year(year: u64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
 /// Takes in a year (e.g. 2019) and returns the number of days in that year.
-pub fn days_in_year(year: u64) -> u64 {
-    if year % 400 == 0 {
-        366
-    } else if year % 100 == 0 {
-        365
-    } else if year % 4 == 0 {
+pub const fn days_in_year(year: u64) -> u64 {
+    if is_leap_year(year) {
         366
     } else {
         365
     }
 }
 
+/// Returns the number of leap years in the range `start_year..end_year`, i.e.
+/// including `start_year` but excluding `end_year`. Returns 0 if `end_year` is not
+/// after `start_year`.
+pub fn leap_years_between(start_year: u64, end_year: u64) -> u64 {
+    leap_years_before(end_year).saturating_sub(leap_years_before(start_year))
+}
+
 /// Takes in a year and month (e.g. 2020, February) and returns the number of days in that month.
 pub fn days_in_month(year: u64, month: Month) -> u64 {
     match month {
         Month::January => 31,
-        Month::February if days_in_year(year) == 366 => 29,
+        Month::February if is_leap_year(year) => 29,
         Month::February => 28,
         Month::March => 31,
         Month::April => 30,
@@ -115,8 +407,35 @@ pub fn days_in_month(year: u64, month: Month) -> u64 {
     }
 }
 
+/// Takes in a year and a range of months within it, and returns the total number
+/// of days from the start of the `start` month to the end of the `end` month
+/// (inclusive). Returns `None` if `end` is before `start`.
+pub fn days_in_months(year: u64, start: Month, end: Month) -> Option<u64> {
+    let (start_index, end_index) = (index_from_month(start), index_from_month(end));
+    if end_index < start_index {
+        return None;
+    }
+    Some(
+        days_before_month(year, end_index) + days_in_month(year, end)
+            - days_before_month(year, start_index),
+    )
+}
+
+/// Takes in a year and a month index in the range 1-12 and returns the number of
+/// days in that year before the start of that month, e.g. 0 for January and 31 for
+/// February. Panics if the month index is outside the 1-12 range.
+pub const fn days_before_month(year: u64, month_index: u64) -> u64 {
+    const CUMULATIVE: [u64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let days = CUMULATIVE[month_index as usize - 1];
+    if is_leap_year(year) && month_index > 2 {
+        days + 1
+    } else {
+        days
+    }
+}
+
 /// Converts a `Month` enum to an integer in the range 1-12.
-pub fn index_from_month(month: Month) -> u64 {
+pub const fn index_from_month(month: Month) -> u64 {
     match month {
         Month::January => 1,
         Month::February => 2,
@@ -135,7 +454,7 @@ pub fn index_from_month(month: Month) -> u64 {
 
 /// Converts an integer in the range 1-12 into the corresponding `Month` enum.
 /// Values outside the 1-12 range are converted to `None`.
-pub fn month_from_index(index: u64) -> Option<Month> {
+pub const fn month_from_index(index: u64) -> Option<Month> {
     match index {
         1 => Some(Month::January),
         2 => Some(Month::February),
@@ -153,194 +472,4838 @@ pub fn month_from_index(index: u64) -> Option<Month> {
     }
 }
 
+/// Takes in a year and quarter (1-4) and returns the number of days in that quarter.
+/// Values of quarter outside the 1-4 range result in `None`.
+pub fn days_in_quarter(year: u64, quarter: u64) -> Option<u64> {
+    if !(1..=4).contains(&quarter) {
+        return None;
+    }
+    let first = (quarter - 1) * 3 + 1;
+    Some(
+        (first..first + 3)
+            .filter_map(month_from_index)
+            .map(|month| days_in_month(year, month))
+            .sum(),
+    )
+}
+
+/// Returns approximate dates of the March equinox, June solstice, September
+/// equinox and December solstice (as seen in UTC) for the given year, in that
+/// order. These are the fixed dates March 20, June 21, September 22 and December
+/// 21, which are not astronomically exact: the actual events can fall a day
+/// either side of these. They are intended as astronomical season boundaries, as
+/// opposed to the meteorological ones used by `PostEpochTime::season_bounds`.
+pub fn approximate_solstice_equinox(_year: u64) -> [(Month, u64); 4] {
+    [
+        (Month::March, 20),
+        (Month::June, 21),
+        (Month::September, 22),
+        (Month::December, 21),
+    ]
+}
+
+// Weekday index (as per `index_from_day`) of December 31 of the given year. The
+// Gregorian calendar repeats every 400 years so only the year modulo 400 matters.
+fn dec31_weekday(year: u64) -> u64 {
+    let y = year % 400;
+    (y + y / 4 - y / 100 + y / 400) % 7
+}
+
+/// Takes in a year and a day of the week, and returns every date in that year
+/// which falls on that day of the week, as (month, day of month) pairs in
+/// chronological order.
+pub fn weekdays_in_year(year: u64, target: Day) -> Vec<(Month, u64)> {
+    let jan1 = (dec31_weekday(year % 400 + 399) + 1) % 7;
+    let first = (index_from_day(target) + 7 - jan1) % 7;
+    (first..days_in_year(year))
+        .step_by(7)
+        .map(|day| {
+            let (month, day) = split_month(year, day);
+            (month, day + 1)
+        })
+        .collect()
+}
+
+/// Takes in a year and returns the number of weeks (52 or 53) in the ISO 8601
+/// week-numbering year of the same number. A year has 53 ISO weeks if it starts
+/// on a Thursday, or if it is a leap year that starts on a Wednesday.
+pub fn iso_weeks_in_year(year: u64) -> u64 {
+    if dec31_weekday(year) == 4 || dec31_weekday(year % 400 + 399) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// Takes in a date and returns the day of the week it falls on, or `None` if the
+/// day is not valid for the given month and year. This works for any year in the
+/// (proleptic) Gregorian calendar, including years before the unix epoch.
+pub fn weekday_of_date(year: u64, month: Month, day: u64) -> Option<Day> {
+    if day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(day_of_week_for_date(year, month, day))
+}
+
+/// Takes in a date and returns the day of the week it falls on. This agrees with
+/// `PostEpochTime::day_of_week` for any point in time on the same date, but also
+/// works for years before the unix epoch. Unlike `weekday_of_date` the day is not
+/// validated; days past the end of the month roll over into the following month,
+/// and day 0 is the last day of the previous month.
+pub fn day_of_week_for_date(year: u64, month: Month, day_of_month: u64) -> Day {
+    // Weekday of the day before the first of the month, i.e. "day 0"
+    let jan1 = (dec31_weekday(year % 400 + 399) + 1) % 7;
+    let day0 = (jan1 + days_before_month(year, index_from_month(month)) + 6) % 7;
+    match day_from_index((day0 + day_of_month % 7) % 7) {
+        Some(day) => day,
+        None => unreachable!("Index is always less than 7"),
+    }
+}
+
 /// Returns the number of seconds in a day.
-pub fn seconds_in_day() -> u64 {
+pub const fn seconds_in_day() -> u64 {
     24 * 60 * 60
 }
 
 /// Returns the number of seconds in an hour.
-pub fn seconds_in_hour() -> u64 {
+pub const fn seconds_in_hour() -> u64 {
     60 * 60
 }
 
 /// Returns the number of seconds in a minute.
-pub fn seconds_in_minute() -> u64 {
+pub const fn seconds_in_minute() -> u64 {
     60
 }
 
-/// Conceptually this is a thin wrapper for `std::time::SystemTime`, but provides
-/// more useful functions. The impl of this struct has functions that allow easily
-/// extracting the year/month/date/etc. for the given point in time. In actual fact
-/// the internal representation of this struct is a `Duration` since the unix epoch,
-/// so that error-handling is only required once upon creating the instance, and
-/// not for each attempt at extracting date/time fields.
-pub struct PostEpochTime {
-    delta: Duration,
+/// The error type returned when a `PostEpochTime` cannot be created.
+#[derive(Debug)]
+pub enum DateTimeError {
+    /// The `SystemTime` was earlier than the unix epoch.
+    #[cfg(feature = "std")]
+    SystemTime(SystemTimeError),
+    /// The input string could not be parsed.
+    Parse(ParseError),
+    /// A date or time field was outside of its valid range. Contains the name
+    /// of the offending field, e.g. "year".
+    OutOfRange(&'static str),
 }
 
-impl PostEpochTime {
-    /// Create a `PostEpochTime` from a `SystemTime`. The `SystemTime` must be temporally
-    /// in the future relative to the unix epoch, or an error will be returned.
-    pub fn from(st: &SystemTime) -> Result<Self, SystemTimeError> {
-        Ok(PostEpochTime {
-            delta: st.duration_since(SystemTime::UNIX_EPOCH)?,
-        })
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            DateTimeError::SystemTime(e) => write!(f, "{}", e),
+            DateTimeError::Parse(e) => write!(f, "{}", e),
+            DateTimeError::OutOfRange(field) => write!(f, "{} is out of range", field),
+        }
     }
+}
 
-    /// Create a `PostEpochTime` for the current instant. The current instant must be
-    /// in the future relative to the unix epoch, or an error will be returned.
-    pub fn now() -> Result<Self, SystemTimeError> {
-        Self::from(&SystemTime::now())
+impl Error for DateTimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            DateTimeError::SystemTime(e) => Some(e),
+            DateTimeError::Parse(e) => Some(e),
+            _ => None,
+        }
     }
+}
 
-    /// Returns the number of milliseconds passed since the unix epoch.
-    pub fn milliseconds_since_epoch(&self) -> u128 {
-        self.delta.as_millis()
+#[cfg(feature = "std")]
+impl From<SystemTimeError> for DateTimeError {
+    fn from(e: SystemTimeError) -> Self {
+        DateTimeError::SystemTime(e)
     }
+}
 
-    /// Returns the number of microseconds passed since the unix epoch.
-    pub fn microseconds_since_epoch(&self) -> u128 {
-        self.delta.as_micros()
+/// Describes the magnitude of a duration in words using the largest whole unit
+/// that fits (days, hours, minutes, or seconds), e.g. "1 day" or "3 hours".
+/// Any remainder smaller than that unit is dropped, so 90 minutes is "1 hour".
+/// Durations of less than a second are "0 seconds".
+pub fn duration_phrase(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= seconds_in_day() {
+        plural(secs / seconds_in_day(), "day")
+    } else if secs >= seconds_in_hour() {
+        plural(secs / seconds_in_hour(), "hour")
+    } else if secs >= seconds_in_minute() {
+        plural(secs / seconds_in_minute(), "minute")
+    } else {
+        plural(secs, "second")
     }
+}
 
-    /// Returns the number of nanoseconds passed since the unix epoch.
-    pub fn nanoseconds_since_epoch(&self) -> u128 {
-        self.delta.as_nanos()
+// Formats a count of some unit, e.g. "1 day" or "3 days".
+fn plural(count: u64, unit: &str) -> String {
+    if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
     }
+}
 
-    /// Returns the number of complete seconds passed since the unix epoch.
-    pub fn seconds_since_epoch(&self) -> u64 {
-        self.delta.as_secs()
+/// Formats a duration as zero-padded hours, minutes and seconds, e.g. "01:02:03".
+/// The hours are not wrapped at a day, so 27 hours is "27:00:00", and they grow
+/// beyond two digits as needed. Any sub-second part is dropped.
+pub fn format_hms(d: Duration) -> String {
+    let secs = d.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / seconds_in_hour(),
+        secs % seconds_in_hour() / seconds_in_minute(),
+        secs % seconds_in_minute()
+    )
+}
+
+/// Returns the English ordinal suffix for a day of the month, i.e. "st", "nd",
+/// "rd" or "th", so that 1 is "1st", 12 is "12th" and 22 is "22nd".
+pub fn ordinal_suffix(day_of_month: u64) -> &'static str {
+    match (day_of_month % 10, day_of_month % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
     }
+}
 
-    /// Returns the number of complete days passed since the unix epoch.
-    pub fn days_since_epoch(&self) -> u64 {
-        self.delta.as_secs() / seconds_in_day()
+/// Takes in a number of days since the unix epoch (i.e. 0 for January 1, 1970)
+/// and returns the day of the week that day falls on.
+pub fn weekday_from_epoch_day(day_index: u64) -> Day {
+    // The unix epoch was a Thursday, which has index 4
+    match day_from_index((day_index % 7 + 4) % 7) {
+        Some(day) => day,
+        None => unreachable!("Index is always less than 7"),
     }
+}
 
-    /// Returns the day of the week that this point in time falls on.
-    pub fn day_of_week(&self) -> Day {
-        match self.days_since_epoch() % 7 {
-            0 => Day::Thursday,
-            1 => Day::Friday,
-            2 => Day::Saturday,
-            3 => Day::Sunday,
-            4 => Day::Monday,
-            5 => Day::Tuesday,
-            6 => Day::Wednesday,
-            _ => panic!("Modulo operator is broken"),
+// Number of leap years in the range 0..year, noting that year 0 is a leap year.
+fn leap_years_before(year: u64) -> u64 {
+    match year {
+        0 => 0,
+        _ => {
+            let y = year - 1;
+            y / 4 - y / 100 + y / 400 + 1
         }
     }
+}
 
-    fn year_split(&self) -> (u64, u64) {
-        let mut days = self.days_since_epoch();
-        let mut year = 1970;
-        loop {
-            let in_year = days_in_year(year);
-            if days < in_year {
-                break;
-            }
-            days -= in_year;
-            year += 1;
-        }
-        (year, days)
+// Number of days from the unix epoch to January 1 of the given year, which must be
+// within the MIN_YEAR..=MAX_YEAR range.
+fn days_before_year(year: u64) -> u64 {
+    let min = PostEpochTime::MIN_YEAR;
+    (year - min) * 365 + leap_years_before(year) - leap_years_before(min)
+}
+
+// Number of days from the unix epoch to the given date, which must already have
+// been validated.
+fn epoch_day(year: u64, month: Month, day: u64) -> u64 {
+    days_before_year(year) + days_before_month(year, index_from_month(month)) + day - 1
+}
+
+// Number of days from the unix epoch to the given date, validating the date fields.
+fn days_from_date(year: u64, month: Month, day: u64) -> Result<u64, DateTimeError> {
+    if !(PostEpochTime::MIN_YEAR..=PostEpochTime::MAX_YEAR).contains(&year) {
+        return Err(DateTimeError::OutOfRange("year"));
     }
+    if day < 1 || day > days_in_month(year, month) {
+        return Err(DateTimeError::OutOfRange("day"));
+    }
+    Ok(epoch_day(year, month, day))
+}
 
-    /// Returns the year (e.g. 2020) this point in time falls on.
-    pub fn year(&self) -> u64 {
-        self.year_split().0
+/// Takes in a date and returns the number of days from the unix epoch to that
+/// date, i.e. 0 for January 1, 1970. Returns `None` if the day is not valid for
+/// the given month and year, or if the date is before the unix epoch.
+pub fn epoch_day_of_date(year: u64, month: Month, day: u64) -> Option<u64> {
+    days_from_date(year, month, day).ok()
+}
+
+// Number of seconds within a day for the given time fields, validating them.
+fn seconds_from_time(hour: u64, minute: u64, second: u64) -> Result<u64, DateTimeError> {
+    if hour >= 24 {
+        return Err(DateTimeError::OutOfRange("hour"));
+    }
+    if minute >= 60 {
+        return Err(DateTimeError::OutOfRange("minute"));
     }
+    if second >= 60 {
+        return Err(DateTimeError::OutOfRange("second"));
+    }
+    Ok(hour * seconds_in_hour() + minute * seconds_in_minute() + second)
+}
 
-    /// Returns the day of the year for this point in time (1-indexed).
-    /// A return value of 1 indicates January 1, a value of 2 indicates January 2,
-    /// and so on. If the year is a leap year the largest returned value
-    /// would be 366, and for non-leap years it would be 365.
-    pub fn day_of_year(&self) -> u64 {
-        self.year_split().1 + 1
+// The Gregorian calendar repeats every 400 years, and any 400 consecutive years
+// contain this many days. It is also a whole number of weeks.
+const DAYS_IN_400_YEARS: u64 = 146_097;
+
+// Splits a number of days since the unix epoch into the year and the (0-indexed)
+// day within that year.
+fn split_year(days: u64) -> (u64, u64) {
+    // Every 400 year cycle has the same number of days, so this estimate is
+    // within a year of the answer and needs at most one correction either way.
+    let mut year = PostEpochTime::MIN_YEAR + days * 400 / DAYS_IN_400_YEARS;
+    if days_before_year(year) > days {
+        year -= 1;
+    } else if days_before_year(year + 1) <= days {
+        year += 1;
     }
+    (year, days - days_before_year(year))
+}
 
-    fn month_split(&self) -> (Month, u64) {
-        let (year, mut days) = self.year_split();
-        let mut month = Month::January;
-        loop {
-            let in_month = days_in_month(year, month);
-            if days < in_month {
-                break;
+// Splits a (0-indexed) day within the given year into the month and the (0-indexed)
+// day within that month.
+fn split_month(year: u64, days: u64) -> (Month, u64) {
+    const MONTHS: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+    // No month is longer than 31 days, so this estimate is never too late, and
+    // it trails the answer by at most one month.
+    let mut index = (days / 31 + 1).min(12);
+    if index < 12 && days >= days_before_month(year, index + 1) {
+        index += 1;
+    }
+    (
+        MONTHS[index as usize - 1],
+        days - days_before_month(year, index),
+    )
+}
+
+/// The calendar fields of a point in time, as produced by `decompose_many`.
+#[derive(Debug, Clone, Copy)]
+pub struct DateTimeFields {
+    /// The year, e.g. 2020.
+    pub year: u64,
+    /// The month.
+    pub month: Month,
+    /// The day of the month (1-indexed).
+    pub day_of_month: u64,
+    /// The hour within the day, in the range 0..23 (inclusive).
+    pub hour: u64,
+    /// The minute within the hour, in the range 0..59 (inclusive).
+    pub minute: u64,
+    /// The second within the minute, in the range 0..59 (inclusive).
+    pub second: u64,
+}
+
+/// Splits a timestamp, given as seconds since the unix epoch, into its calendar
+/// components in a single pass. The returned tuple is (year, month, day of month,
+/// hour, minute, second), matching the values of the corresponding accessors on
+/// `PostEpochTime`.
+pub fn components_of_epoch_secs(secs: u64) -> (u64, Month, u64, u64, u64, u64) {
+    let (year, day_of_year) = split_year(secs / seconds_in_day());
+    let (month, day) = split_month(year, day_of_year);
+    let second_in_day = secs % seconds_in_day();
+    (
+        year,
+        month,
+        day + 1,
+        second_in_day / seconds_in_hour(),
+        second_in_day % seconds_in_hour() / seconds_in_minute(),
+        second_in_day % seconds_in_minute(),
+    )
+}
+
+/// Converts many timestamps, given as seconds since the unix epoch, into their
+/// calendar fields. This is cheaper than constructing a `PostEpochTime` for
+/// each value and reading its accessors, because the year computation is shared
+/// between consecutive timestamps that fall in the same year. Input that is
+/// sorted (or mostly clustered by year) benefits the most, but any order works.
+pub fn decompose_many(secs: &[u64]) -> Vec<DateTimeFields> {
+    // (year, first epoch day of the year, days in the year)
+    let mut cached: Option<(u64, u64, u64)> = None;
+    secs.iter()
+        .map(|&secs| {
+            let days = secs / seconds_in_day();
+            let (year, start) = match cached {
+                Some((year, start, len)) if days >= start && days - start < len => (year, start),
+                _ => {
+                    let (year, day_of_year) = split_year(days);
+                    let start = days - day_of_year;
+                    cached = Some((year, start, days_in_year(year)));
+                    (year, start)
+                }
+            };
+            let (month, day) = split_month(year, days - start);
+            let second_in_day = secs % seconds_in_day();
+            DateTimeFields {
+                year,
+                month,
+                day_of_month: day + 1,
+                hour: second_in_day / seconds_in_hour(),
+                minute: second_in_day % seconds_in_hour() / seconds_in_minute(),
+                second: second_in_day % seconds_in_minute(),
             }
-            days -= in_month;
-            month =
-                month_from_index(index_from_month(month) + 1).expect("Month should never overflow");
+        })
+        .collect()
+}
+
+/// Returns the length of the intersection of two time ranges, each given as a
+/// (start, end) pair. Ranges are half-open, i.e. they include their start but
+/// not their end, so two ranges where one ends exactly when the other starts do
+/// not overlap. Returns `None` if the ranges do not overlap. A range whose end is
+/// not after its start is empty and never overlaps anything.
+pub fn range_overlap(
+    a: (&PostEpochTime, &PostEpochTime),
+    b: (&PostEpochTime, &PostEpochTime),
+) -> Option<Duration> {
+    let start = a.0.delta.max(b.0.delta);
+    let end = a.1.delta.min(b.1.delta);
+    if end > start {
+        Some(end - start)
+    } else {
+        None
+    }
+}
+
+// The Modified Julian Date of 1970-01-01T00:00:00Z.
+const MJD_OF_UNIX_EPOCH: f64 = 40587.0;
+
+// The Julian Date of 1970-01-01T00:00:00Z, and the Julian Day Number of that day.
+const JD_OF_UNIX_EPOCH: f64 = 2440587.5;
+const JDN_OF_UNIX_EPOCH: i64 = 2440588;
+
+/// Takes in a date in the proleptic Gregorian calendar and returns its integer
+/// Julian Day Number, i.e. the Julian Date at noon of that day. The year uses
+/// astronomical numbering, so 0 is 1 BCE. Returns `None` if the day is not valid
+/// for the given month and year.
+pub fn jdn_from_ymd(year: i64, month: Month, day: u64) -> Option<i64> {
+    let days = EpochTime::from_components(year, month, day, 0, 0, 0)
+        .ok()?
+        .days_since_epoch();
+    days.checked_add(JDN_OF_UNIX_EPOCH)
+}
+
+/// The broad category of a `ParseError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input does not have the expected layout, e.g. a separator is missing
+    /// or there are trailing characters.
+    Malformed,
+    /// A digit was expected but something else was found.
+    NonNumeric,
+    /// A field was parsed but its value is not valid, e.g. a month of 13, or the
+    /// resulting time is before the unix epoch.
+    OutOfRange,
+}
+
+/// The error type returned when a string cannot be parsed as a date or time.
+/// It records the byte offset in the input at which the problem was found, the
+/// kind of problem, and a short description of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    kind: ParseErrorKind,
+    reason: &'static str,
+}
+
+impl ParseError {
+    fn new(position: usize, kind: ParseErrorKind, reason: &'static str) -> Self {
+        ParseError {
+            position,
+            kind,
+            reason,
         }
-        (month, days)
     }
 
-    /// Returns the month this point in time falls on.
-    pub fn month(&self) -> Month {
-        self.month_split().0
+    /// Returns the byte offset in the input at which the problem was found.
+    pub fn position(&self) -> usize {
+        self.position
     }
 
-    /// Returns the day of the month for this point in time (1-indexed).
-    /// A return value of 1 means it falls on the first of the month. The maximum
-    /// returned value will be 31.
-    pub fn day_of_month(&self) -> u64 {
-        self.month_split().1 + 1
+    /// Returns the kind of problem that was found.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
     }
 
-    /// Returns the second within the day (0-indexed). This will be in the range
-    /// 0..86399 (inclusive).
-    pub fn second_in_day(&self) -> u64 {
-        self.delta.as_secs() % seconds_in_day()
+    /// Returns a short description of the problem, e.g. "expected digit".
+    pub fn reason(&self) -> &'static str {
+        self.reason
     }
+}
 
-    /// Returns the hour within the day (0-indexed). This will be in the range
-    /// 0..23 (inclusive).
-    pub fn hour(&self) -> u64 {
-        self.second_in_day() / seconds_in_hour()
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.reason, self.position)
     }
+}
 
-    /// Returns the second within the hour (0-indexed). This will be in the range
-    /// 0..3599 (inclusive).
-    pub fn second_in_hour(&self) -> u64 {
-        self.second_in_day() % seconds_in_hour()
+impl Error for ParseError {}
+
+impl From<ParseError> for DateTimeError {
+    fn from(e: ParseError) -> Self {
+        DateTimeError::Parse(e)
     }
+}
 
-    /// Returns the minute within the hour (0-indexed). This will be in the range
-    /// 0..59 (inclusive).
-    pub fn minute(&self) -> u64 {
-        self.second_in_hour() / seconds_in_minute()
+// A minimal cursor over the bytes of an input string, used by the parsers.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(s: &'a str) -> Self {
+        Scanner {
+            bytes: s.as_bytes(),
+            pos: 0,
+        }
     }
 
-    /// Returns the second within the minute (0-indexed). This will be in the range
-    /// 0..59 (inclusive).
-    pub fn second(&self) -> u64 {
-        self.delta.as_secs() % seconds_in_minute()
+    fn error(&self, reason: &'static str) -> ParseError {
+        ParseError::new(self.pos, ParseErrorKind::Malformed, reason)
     }
-}
 
-impl fmt::Display for PostEpochTime {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {} {} {} {:02}:{:02}:{:02}",
-            day_abbrev_string(self.day_of_week()),
-            self.day_of_month(),
-            month_abbrev_string(self.month()),
-            self.year(),
-            self.hour(),
-            self.minute(),
-            self.second()
-        )
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn finish(&self) -> Result<(), ParseError> {
+        if self.pos == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing characters"))
+        }
+    }
 
-    #[test]
-    fn smoke_test() {
-        let timestamp = SystemTime::UNIX_EPOCH + Duration::new(1580610340, 123);
-        let pet = PostEpochTime::from(&timestamp).unwrap();
-        assert_eq!(format!("{}", pet), "Sun, 2 Feb 2020 02:25:40".to_string());
+    // Consumes the next byte if it is one of `expected`.
+    fn accept(&mut self, expected: &[u8]) -> Option<u8> {
+        let b = self.peek().filter(|b| expected.contains(b))?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn expect(&mut self, expected: &[u8], reason: &'static str) -> Result<u8, ParseError> {
+        self.accept(expected).ok_or_else(|| self.error(reason))
+    }
+
+    // Consumes exactly `count` ASCII digits and returns their value.
+    fn digits(&mut self, count: usize) -> Result<u64, ParseError> {
+        let mut value = 0;
+        for _ in 0..count {
+            match self.peek() {
+                Some(b @ b'0'..=b'9') => {
+                    value = value * 10 + u64::from(b - b'0');
+                    self.pos += 1;
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        self.pos,
+                        ParseErrorKind::NonNumeric,
+                        "expected digit",
+                    ))
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    // Consumes one or more ASCII digits, up to a maximum of `max_count`, and
+    // returns their value.
+    fn some_digits(&mut self, max_count: usize) -> Result<u64, ParseError> {
+        let mut value = self.digits(1)?;
+        for _ in 1..max_count {
+            match self.peek() {
+                Some(b @ b'0'..=b'9') => {
+                    value = value * 10 + u64::from(b - b'0');
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        Ok(value)
+    }
+
+    // Like `digits`, but also checks the value is within `min..=max`, reporting
+    // the position of the start of the field if it is not.
+    fn field(
+        &mut self,
+        count: usize,
+        min: u64,
+        max: u64,
+        reason: &'static str,
+    ) -> Result<u64, ParseError> {
+        let start = self.pos;
+        let value = self.digits(count)?;
+        if value < min || value > max {
+            return Err(ParseError::new(start, ParseErrorKind::OutOfRange, reason));
+        }
+        Ok(value)
+    }
+
+    // Consumes a date in the form YYYY-MM-DD. The year is not range-checked.
+    fn date(&mut self) -> Result<(u64, Month, u64), ParseError> {
+        let year = self.digits(4)?;
+        self.expect(b"-", "expected '-'")?;
+        let month = self.field(2, 1, 12, "month out of range")?;
+        let month = month_from_index(month).expect("Month was range-checked");
+        self.expect(b"-", "expected '-'")?;
+        let day = self.field(2, 1, days_in_month(year, month), "day out of range")?;
+        Ok((year, month, day))
+    }
+
+    // Consumes a time in the form HH:MM:SS and returns the number of seconds
+    // into the day.
+    fn time(&mut self) -> Result<u64, ParseError> {
+        let hour = self.field(2, 0, 23, "hour out of range")?;
+        self.expect(b":", "expected ':'")?;
+        let minute = self.field(2, 0, 59, "minute out of range")?;
+        self.expect(b":", "expected ':'")?;
+        let second = self.field(2, 0, 59, "second out of range")?;
+        Ok(hour * seconds_in_hour() + minute * seconds_in_minute() + second)
+    }
+}
+
+/// Conceptually this is a thin wrapper for `std::time::SystemTime`, but provides
+/// more useful functions. The impl of this struct has functions that allow easily
+/// extracting the year/month/date/etc. for the given point in time. In actual fact
+/// the internal representation of this struct is a `Duration` since the unix epoch,
+/// so that error-handling is only required once upon creating the instance, and
+/// not for each attempt at extracting date/time fields.
+///
+/// All of the date/time fields are in UTC. To view a point in time in the local
+/// time of some other UTC offset, use `with_offset` to get a `LocalDateTime`.
+///
+/// Points in time compare and sort chronologically, and can be hashed, e.g. for
+/// use as `HashMap` keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostEpochTime {
+    delta: Duration,
+}
+
+impl PostEpochTime {
+    /// The earliest year that a `PostEpochTime` can fall on, i.e. the year of the
+    /// unix epoch. All years are interpreted using the Gregorian leap year rules,
+    /// so there is no notion of a year zero or of BCE years.
+    pub const MIN_YEAR: u64 = 1970;
+
+    /// The latest year that a `PostEpochTime` can fall on. The internal `Duration`
+    /// runs out partway through this year (in November), so constructors may
+    /// reject dates late in this year even though the year itself is accepted.
+    pub const MAX_YEAR: u64 = 584_554_051_223;
+
+    /// The latest representable `PostEpochTime`, corresponding to `Duration::MAX`
+    /// after the unix epoch. Its `year()` is `MAX_YEAR`.
+    pub const MAX: PostEpochTime = PostEpochTime {
+        delta: Duration::MAX,
+    };
+
+    /// Create a `PostEpochTime` from a `SystemTime`. The `SystemTime` must be temporally
+    /// in the future relative to the unix epoch, or an error will be returned.
+    #[cfg(feature = "std")]
+    pub fn from(st: &SystemTime) -> Result<Self, DateTimeError> {
+        Ok(PostEpochTime {
+            delta: st.duration_since(SystemTime::UNIX_EPOCH)?,
+        })
+    }
+
+    /// Create a `PostEpochTime` from a `SystemTime`, clamping it to the unix epoch
+    /// if it is earlier. This is useful for times that may be slightly before the
+    /// epoch because of clock skew.
+    #[cfg(feature = "std")]
+    pub fn from_saturating(st: &SystemTime) -> Self {
+        PostEpochTime {
+            delta: st
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO),
+        }
+    }
+
+    /// Create a `PostEpochTime` for the current instant. The current instant must be
+    /// in the future relative to the unix epoch, or an error will be returned.
+    #[cfg(feature = "std")]
+    pub fn now() -> Result<Self, DateTimeError> {
+        Self::from(&SystemTime::now())
+    }
+
+    /// Create a `PostEpochTime` from a number of seconds since the unix epoch. This
+    /// is the inverse of `seconds_since_epoch()`.
+    pub fn from_seconds_since_epoch(secs: u64) -> Self {
+        PostEpochTime {
+            delta: Duration::from_secs(secs),
+        }
+    }
+
+    /// Create a `PostEpochTime` from a number of milliseconds since the unix epoch.
+    /// This is the inverse of `milliseconds_since_epoch()`. This panics if the
+    /// value is too large to be represented, i.e. if it is later than `MAX`.
+    pub fn from_millis_since_epoch(millis: u128) -> Self {
+        let secs = u64::try_from(millis / 1000).expect("milliseconds out of range");
+        PostEpochTime {
+            delta: Duration::new(secs, (millis % 1000) as u32 * 1_000_000),
+        }
+    }
+
+    /// Create a `PostEpochTime` at midnight at the start of the given day, counted
+    /// in days since the unix epoch. This is the inverse of `days_since_epoch()`.
+    /// This panics if the value is too large to be represented.
+    pub fn from_days_since_epoch(days: u64) -> Self {
+        let secs = days
+            .checked_mul(seconds_in_day())
+            .expect("days out of range");
+        PostEpochTime::from_seconds_since_epoch(secs)
+    }
+
+    /// Create a `PostEpochTime` from the given calendar date and time of day (in
+    /// UTC). Returns an error naming the offending field if the year is before
+    /// 1970, the day is not within the month, the hour is not less than 24, or
+    /// the minute or second is not less than 60. Very large years that can't be
+    /// represented also produce an error rather than overflowing.
+    pub fn from_components(
+        year: u64,
+        month: Month,
+        day_of_month: u64,
+        hour: u64,
+        minute: u64,
+        second: u64,
+    ) -> Result<Self, DateTimeError> {
+        let days = days_from_date(year, month, day_of_month)?;
+        let time = seconds_from_time(hour, minute, second)?;
+        let secs = days
+            .checked_mul(seconds_in_day())
+            .and_then(|secs| secs.checked_add(time))
+            .ok_or(DateTimeError::OutOfRange("date"))?;
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(secs),
+        })
+    }
+
+    /// Create a `PostEpochTime` from a year, a (1-indexed) day of the year, and a
+    /// second within that day. This is the inverse of `(year(), day_of_year(),
+    /// second_in_day())`. Returns an error naming the offending field if the year
+    /// is before 1970, the day is not within the year, or the second is not less
+    /// than `seconds_in_day()`.
+    pub fn from_year_ordinal_second(year: u64, doy: u64, sod: u64) -> Result<Self, DateTimeError> {
+        if !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year) {
+            return Err(DateTimeError::OutOfRange("year"));
+        }
+        if doy < 1 || doy > days_in_year(year) {
+            return Err(DateTimeError::OutOfRange("day of year"));
+        }
+        if sod >= seconds_in_day() {
+            return Err(DateTimeError::OutOfRange("second of day"));
+        }
+        let secs = (days_before_year(year) + doy - 1)
+            .checked_mul(seconds_in_day())
+            .and_then(|secs| secs.checked_add(sod))
+            .ok_or(DateTimeError::OutOfRange("date"))?;
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(secs),
+        })
+    }
+
+    /// Create a `PostEpochTime` from a Modified Julian Date, i.e. a (possibly
+    /// fractional) number of days since midnight on November 17, 1858. Note that
+    /// unlike the Julian Date, the MJD starts at midnight rather than noon.
+    /// Returns an error if the date is before the unix epoch (MJD 40587), is not a
+    /// finite number, or is too large to represent.
+    pub fn from_mjd(mjd: f64) -> Result<Self, DateTimeError> {
+        let secs = (mjd - MJD_OF_UNIX_EPOCH) * seconds_in_day() as f64;
+        let delta =
+            Duration::try_from_secs_f64(secs).map_err(|_| DateTimeError::OutOfRange("date"))?;
+        Ok(PostEpochTime { delta })
+    }
+
+    /// Returns a builder for creating a `PostEpochTime` from calendar components
+    /// one at a time. See `PostEpochTimeBuilder` for the defaults used for fields
+    /// that are not set.
+    pub fn builder() -> PostEpochTimeBuilder {
+        PostEpochTimeBuilder::default()
+    }
+
+    /// Parses an ISO 8601 ordinal date (e.g. "2020-033") into a `PostEpochTime` at
+    /// midnight of that day. Returns an error if the string is malformed, the year is
+    /// before 1970, or the day is not within the year.
+    pub fn parse_iso_ordinal(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let year = scanner.field(4, Self::MIN_YEAR, 9999, "year is before 1970")?;
+        scanner.expect(b"-", "expected '-'")?;
+        let day = scanner.field(3, 1, days_in_year(year), "day of year out of range")?;
+        scanner.finish()?;
+        let days = days_before_year(year) + day - 1;
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(days * seconds_in_day()),
+        })
+    }
+
+    /// Parses a US-style short date with a two-digit year, such as "02/02/20" or
+    /// "2/2/20" (month first), into a `PostEpochTime` at midnight of that day.
+    /// The two-digit year is interpreted using `pivot`: years less than `pivot`
+    /// are taken to be in the 2000s, and the rest in the 1900s. For example with
+    /// a pivot of 70, "12/31/69" is 2069 and "01/01/70" is 1970. Since times
+    /// before 1970 can't be represented, a date that lands in 1900-1969 is an
+    /// error.
+    pub fn parse_us_short(s: &str, pivot: u64) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let month_pos = scanner.pos;
+        let month = month_from_index(scanner.some_digits(2)?).ok_or_else(|| {
+            ParseError::new(month_pos, ParseErrorKind::OutOfRange, "month out of range")
+        })?;
+        scanner.expect(b"/", "expected '/'")?;
+        let day_pos = scanner.pos;
+        let day = scanner.some_digits(2)?;
+        scanner.expect(b"/", "expected '/'")?;
+        let year_pos = scanner.pos;
+        let year = scanner.digits(2)?;
+        scanner.finish()?;
+        let year = if year < pivot {
+            2000 + year
+        } else {
+            1900 + year
+        };
+        if year < Self::MIN_YEAR {
+            return Err(ParseError::new(
+                year_pos,
+                ParseErrorKind::OutOfRange,
+                "year is before 1970",
+            ));
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(ParseError::new(
+                day_pos,
+                ParseErrorKind::OutOfRange,
+                "day out of range",
+            ));
+        }
+        let days = epoch_day(year, month, day);
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(days * seconds_in_day()),
+        })
+    }
+
+    /// Parses an ISO 8601 week date (e.g. "2020-W05-7") into a `PostEpochTime` at
+    /// midnight of that day. The week must exist in the given ISO week-numbering
+    /// year, and the weekday must be in the range 1 (Monday) to 7 (Sunday).
+    /// Returns an error if the string is malformed, a field is out of range, or
+    /// the day is before the unix epoch.
+    pub fn parse_iso_week_date(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let year = scanner.digits(4)?;
+        scanner.expect(b"-", "expected '-'")?;
+        scanner.expect(b"W", "expected 'W'")?;
+        let week = scanner.field(2, 1, iso_weeks_in_year(year), "week out of range")?;
+        scanner.expect(b"-", "expected '-'")?;
+        let weekday = scanner.field(1, 1, 7, "weekday out of range")?;
+        scanner.finish()?;
+        if year < Self::MIN_YEAR {
+            return Err(ParseError::new(
+                0,
+                ParseErrorKind::OutOfRange,
+                "year is before 1970",
+            ));
+        }
+        // January 4 is always in week 1, so find the Monday on or before it.
+        let jan4 = days_before_year(year) + 3;
+        let week1_monday = jan4 as i64 - (iso_weekday(weekday_from_epoch_day(jan4)) as i64 - 1);
+        let days = week1_monday + ((week - 1) * 7 + weekday - 1) as i64;
+        if days < 0 {
+            return Err(ParseError::new(
+                0,
+                ParseErrorKind::OutOfRange,
+                "time is before the unix epoch",
+            ));
+        }
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(days as u64 * seconds_in_day()),
+        })
+    }
+
+    /// Parses an RFC 3339 timestamp such as "2020-02-02T02:25:40Z" into a
+    /// `PostEpochTime`. Fractional seconds (up to nanosecond precision) are
+    /// accepted, as are explicit offsets like "+05:30" or "-08:00"; the time is
+    /// normalized to UTC so only the instant is preserved, not the offset itself.
+    /// Returns an error if the string is malformed, a field is out of range, or
+    /// the instant is before the unix epoch once the offset has been applied.
+    pub fn parse_rfc3339(s: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(s);
+        let (year, month, day) = scanner.date()?;
+        scanner.expect(b"Tt", "expected 'T'")?;
+        let time = scanner.time()?;
+        let mut nanos = 0;
+        if scanner.accept(b".").is_some() {
+            let mut scale = 1_000_000_000;
+            while let Some(b @ b'0'..=b'9') = scanner.peek() {
+                scale /= 10;
+                if scale == 0 {
+                    return Err(scanner.error("too many fractional digits"));
+                }
+                nanos += u32::from(b - b'0') * scale;
+                scanner.pos += 1;
+            }
+            if scale == 1_000_000_000 {
+                return Err(ParseError::new(
+                    scanner.pos,
+                    ParseErrorKind::NonNumeric,
+                    "expected digit",
+                ));
+            }
+        }
+        let offset_secs = match scanner.expect(b"Zz+-", "expected 'Z' or offset")? {
+            sign @ (b'+' | b'-') => {
+                let hour = scanner.field(2, 0, 23, "offset hour out of range")?;
+                scanner.expect(b":", "expected ':'")?;
+                let minute = scanner.field(2, 0, 59, "offset minute out of range")?;
+                let secs = (hour * seconds_in_hour() + minute * seconds_in_minute()) as i64;
+                if sign == b'-' {
+                    -secs
+                } else {
+                    secs
+                }
+            }
+            _ => 0,
+        };
+        scanner.finish()?;
+
+        // Offsets are less than a day, so the last day of 1969 is the only pre-epoch
+        // local date that can still refer to a post-epoch instant.
+        let days = match (year, month, day) {
+            (1969, Month::December, 31) => -1,
+            _ if year < Self::MIN_YEAR => {
+                return Err(ParseError::new(
+                    0,
+                    ParseErrorKind::OutOfRange,
+                    "year is before 1970",
+                ))
+            }
+            _ => epoch_day(year, month, day) as i64,
+        };
+        let utc = days * seconds_in_day() as i64 + time as i64 - offset_secs;
+        if utc < 0 {
+            return Err(ParseError::new(
+                0,
+                ParseErrorKind::OutOfRange,
+                "time is before the unix epoch",
+            ));
+        }
+        Ok(PostEpochTime {
+            delta: Duration::new(utc as u64, nanos),
+        })
+    }
+
+    /// Parses a date in the form "2020-02-02" and a time of day in the form
+    /// "02:25:40", given as separate strings, into a `PostEpochTime` in UTC. Both
+    /// parts must be complete; a time such as "02:25" is an error rather than
+    /// being taken to mean 02:25:00. The position in a returned error refers to
+    /// whichever of the two strings the problem was found in.
+    pub fn from_date_time_strings(date: &str, time: &str) -> Result<Self, ParseError> {
+        let mut scanner = Scanner::new(date);
+        let (year, month, day) = scanner.date()?;
+        scanner.finish()?;
+        if year < Self::MIN_YEAR {
+            return Err(ParseError::new(
+                0,
+                ParseErrorKind::OutOfRange,
+                "year is before 1970",
+            ));
+        }
+        let mut scanner = Scanner::new(time);
+        let time = scanner.time()?;
+        scanner.finish()?;
+        let days = epoch_day(year, month, day);
+        Ok(PostEpochTime {
+            delta: Duration::from_secs(days * seconds_in_day() + time),
+        })
+    }
+
+    /// Converts this point in time back into a `SystemTime`. This is the inverse of
+    /// `PostEpochTime::from`.
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH + self.delta
+    }
+
+    /// Returns the amount of time passed since the unix epoch, at full precision.
+    pub fn as_duration_since_epoch(&self) -> Duration {
+        self.delta
+    }
+
+    /// Returns the number of milliseconds passed since the unix epoch.
+    pub fn milliseconds_since_epoch(&self) -> u128 {
+        self.delta.as_millis()
+    }
+
+    /// Returns the number of microseconds passed since the unix epoch.
+    pub fn microseconds_since_epoch(&self) -> u128 {
+        self.delta.as_micros()
+    }
+
+    /// Returns the number of nanoseconds passed since the unix epoch.
+    pub fn nanoseconds_since_epoch(&self) -> u128 {
+        self.delta.as_nanos()
+    }
+
+    /// Returns the number of complete seconds passed since the unix epoch.
+    pub fn seconds_since_epoch(&self) -> u64 {
+        self.delta.as_secs()
+    }
+
+    /// Returns the number of complete minutes passed since the unix epoch.
+    pub fn minutes_since_epoch(&self) -> u64 {
+        self.delta.as_secs() / seconds_in_minute()
+    }
+
+    /// Returns the number of complete hours passed since the unix epoch.
+    pub fn hours_since_epoch(&self) -> u64 {
+        self.delta.as_secs() / seconds_in_hour()
+    }
+
+    /// Returns the number of complete days passed since the unix epoch.
+    pub fn days_since_epoch(&self) -> u64 {
+        self.delta.as_secs() / seconds_in_day()
+    }
+
+    /// Returns a key identifying the day this point in time falls on. All points
+    /// in time on the same (UTC) day have equal keys.
+    pub fn day_key(&self) -> DayKey {
+        DayKey(self.days_since_epoch())
+    }
+
+    /// Returns the number of complete weeks (7-day periods) passed since the unix
+    /// epoch. Note that the epoch was a Thursday, so these weeks run from Thursday
+    /// to Wednesday.
+    pub fn weeks_since_epoch(&self) -> u64 {
+        self.days_since_epoch() / 7
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to this point in time. This
+    /// panics if `earlier` is later than this point in time; use
+    /// `saturating_duration_since` or `signed_seconds_since` if that is possible.
+    pub fn duration_since(&self, earlier: &PostEpochTime) -> Duration {
+        self.delta
+            .checked_sub(earlier.delta)
+            .expect("earlier is later than self")
+    }
+
+    /// Returns the number of whole seconds from `other` to this point in time,
+    /// which is negative if `other` is later. Sub-second parts are ignored, and the
+    /// result saturates at the limits of `i64`.
+    pub fn signed_seconds_since(&self, other: &PostEpochTime) -> i64 {
+        let diff = i128::from(self.seconds_since_epoch()) - i128::from(other.seconds_since_epoch());
+        diff.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64
+    }
+
+    /// Returns the number of calendar days between the days this point in time
+    /// and `other` fall on, regardless of which one is earlier. This counts
+    /// midnights crossed, so 23:00 and 01:00 the next day are 1 day apart.
+    pub fn days_between(&self, other: &PostEpochTime) -> u64 {
+        self.days_since_epoch().abs_diff(other.days_since_epoch())
+    }
+
+    /// Returns the amount of time elapsed from `other` to this point in time, or
+    /// `Duration::ZERO` if `other` is later than this point in time.
+    pub fn saturating_duration_since(&self, other: &PostEpochTime) -> Duration {
+        self.delta.saturating_sub(other.delta)
+    }
+
+    /// Returns the number of whole seconds from this point in time until `other`,
+    /// or 0 if `other` is earlier than this point in time. This is intended for
+    /// use as `start.seconds_between(&end)`.
+    pub fn seconds_between(&self, other: &PostEpochTime) -> u64 {
+        other.saturating_duration_since(self).as_secs()
+    }
+
+    /// Returns true if this point in time and `other` are no more than `tolerance`
+    /// apart, regardless of which one is earlier.
+    pub fn is_within(&self, other: &PostEpochTime, tolerance: Duration) -> bool {
+        self.delta.abs_diff(other.delta) <= tolerance
+    }
+
+    /// Returns true if this point in time, treated as an expiry time, has passed
+    /// even after allowing for `skew` of clock difference, i.e. if this point in
+    /// time plus `skew` is earlier than the current instant. Returns an error in
+    /// the same cases as `now()`.
+    #[cfg(feature = "std")]
+    pub fn is_expired(&self, skew: Duration) -> Result<bool, DateTimeError> {
+        Ok(self.delta.saturating_add(skew) < Self::now()?.delta)
+    }
+
+    /// Describes this point in time relative to `reference` in words, e.g. "in 3 hours"
+    /// if it is later than `reference` or "2 days ago" if it is earlier. The magnitude
+    /// is rounded down to the largest unit that fits. Differences of at least one
+    /// calendar month are given in whole calendar months or years, e.g. "in 1 year"
+    /// for 13 months; shorter ones are described as with `duration_phrase`. If the
+    /// two are no more than `threshold` apart, or less than a whole second apart,
+    /// this returns "just now" instead, so that a zero difference is never
+    /// described as "in 0 seconds".
+    pub fn humanize_relative_to(&self, reference: &PostEpochTime, threshold: Duration) -> String {
+        let diff = self.delta.abs_diff(reference.delta);
+        if diff <= threshold || diff.as_secs() == 0 {
+            return "just now".to_string();
+        }
+        let (earlier, later) = if self < reference {
+            (self, reference)
+        } else {
+            (reference, self)
+        };
+        let months = earlier.whole_months_until(later);
+        let phrase = match months {
+            0 => duration_phrase(diff),
+            1..=11 => plural(months, "month"),
+            _ => plural(months / 12, "year"),
+        };
+        if self > reference {
+            format!("in {}", phrase)
+        } else {
+            format!("{} ago", phrase)
+        }
+    }
+
+    // Number of whole calendar months from this point in time until `later`, which
+    // must not be earlier. A month is complete once the same day of the month and
+    // time of day is reached, so January 31 to March 1 is one month.
+    fn whole_months_until(&self, later: &PostEpochTime) -> u64 {
+        let (a, b) = (self.decompose(), later.decompose());
+        let months = (b.year() * 12 + index_from_month(b.month()))
+            - (a.year() * 12 + index_from_month(a.month()));
+        let within_month = |d: &DecomposedTime, p: &PostEpochTime| {
+            (d.day_of_month(), p.second_in_day(), p.delta.subsec_nanos())
+        };
+        if within_month(&b, later) < within_month(&a, self) {
+            months - 1
+        } else {
+            months
+        }
+    }
+
+    /// Returns the age in days of something that started at this point in time, as
+    /// of `reference`. This counts midnights crossed rather than elapsed 24 hour
+    /// periods, so a birth at 23:00 is 1 day old at 01:00 the next day. The result
+    /// is negative if `reference` falls on an earlier day.
+    pub fn age_in_days(&self, reference: &PostEpochTime) -> i64 {
+        reference.days_since_epoch() as i64 - self.days_since_epoch() as i64
+    }
+
+    /// Returns the number of calendar days from the day this point in time falls on
+    /// until the given date. This is negative if the date has already passed, and
+    /// zero if it is the same day. Returns an error if the date is not valid or is
+    /// before the unix epoch.
+    pub fn days_until_date(&self, year: u64, month: Month, day: u64) -> Result<i64, DateTimeError> {
+        let target = days_from_date(year, month, day)?;
+        Ok(target as i64 - self.days_since_epoch() as i64)
+    }
+
+    /// Returns the day of the week that this point in time falls on.
+    pub fn day_of_week(&self) -> Day {
+        weekday_from_epoch_day(self.days_since_epoch())
+    }
+
+    /// Returns true if this point in time falls on a Saturday or Sunday.
+    pub fn is_weekend(&self) -> bool {
+        self.day_of_week().is_weekend()
+    }
+
+    /// Returns true if this point in time falls on a Monday through Friday.
+    pub fn is_weekday(&self) -> bool {
+        self.day_of_week().is_weekday()
+    }
+
+    /// Returns the day of the week that the first day of this point in time's
+    /// month falls on.
+    pub fn first_weekday_of_month(&self) -> Day {
+        weekday_from_epoch_day(self.days_since_epoch() - self.month_split().1)
+    }
+
+    /// Returns the position of this point in time's day of the week within a week
+    /// starting on `week_start`, in the range 0..6 (inclusive). For example, with
+    /// a `week_start` of Monday, Monday gives 0 and Sunday gives 6.
+    pub fn weekday_index(&self, week_start: Day) -> u64 {
+        (index_from_day(self.day_of_week()) + 7 - index_from_day(week_start)) % 7
+    }
+
+    /// Returns the number of seconds since the unix epoch at midnight of the most
+    /// recent `week_start` day, which may be the current day. This is useful for
+    /// finding weekly boundaries, e.g. the most recent Sunday 00:00:00. If that
+    /// midnight would be before the unix epoch (i.e. for times in the first days
+    /// of 1970), returns 0.
+    pub fn seconds_at_start_of_week(&self, week_start: Day) -> u64 {
+        let days_back = self.weekday_index(week_start);
+        self.days_since_epoch().saturating_sub(days_back) * seconds_in_day()
+    }
+
+    /// Returns the point in time at midnight of the most recent `week_start` day,
+    /// which may be the current day. This is clamped to the unix epoch in the same
+    /// way as `seconds_at_start_of_week`.
+    pub fn start_of_week(&self, week_start: Day) -> PostEpochTime {
+        PostEpochTime {
+            delta: Duration::from_secs(self.seconds_at_start_of_week(week_start)),
+        }
+    }
+
+    /// Returns the point in time at midnight at the start of this day, i.e. with
+    /// the time of day set to 00:00:00.
+    pub fn previous_midnight(&self) -> PostEpochTime {
+        PostEpochTime {
+            delta: Duration::from_secs(self.days_since_epoch() * seconds_in_day()),
+        }
+    }
+
+    /// Returns the start of the `bucket_secs`-second bucket containing this point in
+    /// time, where buckets are aligned to the unix epoch. For example a bucket
+    /// size of 300 rounds down to the nearest multiple of 5 minutes, dropping any
+    /// sub-second part. A bucket size of 0 is a no-op and returns this point in
+    /// time unchanged.
+    pub fn truncate_to_seconds(&self, bucket_secs: u64) -> PostEpochTime {
+        if bucket_secs == 0 {
+            return *self;
+        }
+        let secs = self.seconds_since_epoch();
+        PostEpochTime {
+            delta: Duration::from_secs(secs - secs % bucket_secs),
+        }
+    }
+
+    /// Returns the point in time at the start of this day. This is the same as
+    /// `previous_midnight()`.
+    pub fn start_of_day(&self) -> PostEpochTime {
+        self.previous_midnight()
+    }
+
+    /// Returns the point in time at the start of this hour, i.e. with the minute,
+    /// second and any sub-second part set to zero.
+    pub fn start_of_hour(&self) -> PostEpochTime {
+        self.truncate_to_seconds(seconds_in_hour())
+    }
+
+    /// Returns the point in time at the start of this minute, i.e. with the
+    /// second and any sub-second part set to zero.
+    pub fn start_of_minute(&self) -> PostEpochTime {
+        self.truncate_to_seconds(seconds_in_minute())
+    }
+
+    /// Returns the point in time at midnight at the start of the following day.
+    /// This panics if that point in time cannot be represented.
+    pub fn next_midnight(&self) -> PostEpochTime {
+        let secs = (self.days_since_epoch() + 1)
+            .checked_mul(seconds_in_day())
+            .expect("next_midnight result out of range");
+        PostEpochTime::from_seconds_since_epoch(secs)
+    }
+
+    /// Returns the point in time on the same day with the time of day clamped to
+    /// the range `min_secs..=max_secs`, given in seconds since midnight. A time of
+    /// day already within the range (including any sub-second part) is left alone,
+    /// so for example clamping to business hours moves 08:15 to 09:00 and 18:30 to
+    /// 17:00 but leaves 12:34:56.789 as-is. This panics if `min_secs` is greater
+    /// than `max_secs`, if `max_secs` is not less than `seconds_in_day()`, or if the
+    /// result cannot be represented.
+    pub fn clamp_time_of_day(&self, min_secs: u64, max_secs: u64) -> PostEpochTime {
+        assert!(max_secs < seconds_in_day(), "max_secs must be within a day");
+        let midnight = self.previous_midnight().delta;
+        let time = (self.delta - midnight)
+            .clamp(Duration::from_secs(min_secs), Duration::from_secs(max_secs));
+        PostEpochTime {
+            delta: midnight + time,
+        }
+    }
+
+    /// Formats this point in time as an RFC 3339 timestamp in UTC without any
+    /// fractional seconds, e.g. "2020-02-02T02:25:40Z". Any sub-second part is
+    /// truncated.
+    pub fn to_rfc3339(&self) -> String {
+        let d = self.decompose();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            d.year(),
+            index_from_month(d.month()),
+            d.day_of_month(),
+            d.hour(),
+            d.minute(),
+            d.second()
+        )
+    }
+
+    /// Formats this point in time as an RFC 3339 timestamp in UTC with the full
+    /// nanosecond precision, i.e. exactly nine fractional digits, e.g.
+    /// "2020-02-02T02:25:40.000000123Z".
+    pub fn to_rfc3339_nanos(&self) -> String {
+        let d = self.decompose();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+            d.year(),
+            index_from_month(d.month()),
+            d.day_of_month(),
+            d.hour(),
+            d.minute(),
+            d.second(),
+            self.nanosecond()
+        )
+    }
+
+    /// Formats this point in time as an ISO 8601 timestamp in UTC with exactly
+    /// three fractional digits. This is the same as `to_rfc3339_millis`, since
+    /// that format is also valid ISO 8601.
+    pub fn to_iso8601_millis(&self) -> String {
+        self.to_rfc3339_millis()
+    }
+
+    /// Formats this point in time as an RFC 3339 timestamp in UTC with exactly
+    /// three fractional digits, e.g. "2020-02-02T02:25:40.123Z". Any precision
+    /// beyond milliseconds is truncated.
+    pub fn to_rfc3339_millis(&self) -> String {
+        self.to_rfc3339_millis_with(FractionRounding::Truncate)
+    }
+
+    /// Same as `to_rfc3339_millis`, but with control over how precision beyond
+    /// milliseconds is dropped. When rounding, the carry propagates into the
+    /// other fields, so 23:59:59.9995 on December 31 becomes midnight of the next
+    /// year. A time within half a millisecond of `PostEpochTime::MAX` is truncated
+    /// rather than rounded, since the rounded time cannot be represented.
+    pub fn to_rfc3339_millis_with(&self, rounding: FractionRounding) -> String {
+        let t = match rounding {
+            FractionRounding::Truncate => *self,
+            FractionRounding::HalfUp => PostEpochTime {
+                delta: self.delta.saturating_add(Duration::from_micros(500)),
+            },
+        };
+        let d = t.decompose();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            d.year(),
+            index_from_month(d.month()),
+            d.day_of_month(),
+            d.hour(),
+            d.minute(),
+            d.second(),
+            t.delta.subsec_millis()
+        )
+    }
+
+    /// Formats this point in time as a compact key whose lexical order matches
+    /// chronological order, e.g. "20200202T022540". If there is a sub-second part
+    /// it is appended as exactly nine digits of nanoseconds, e.g.
+    /// "20200202T022540.123000000"; keys without it still sort before any key
+    /// with it for the same second. The year is zero-padded to four digits, so
+    /// the ordering only holds for years up to 9999, after which the year grows
+    /// wider and sorts out of order.
+    pub fn sortable_key(&self) -> String {
+        let d = self.decompose();
+        let mut key = format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}",
+            d.year(),
+            index_from_month(d.month()),
+            d.day_of_month(),
+            d.hour(),
+            d.minute(),
+            d.second()
+        );
+        let nanos = self.delta.subsec_nanos();
+        if nanos != 0 {
+            key.push_str(&format!(".{:09}", nanos));
+        }
+        key
+    }
+
+    /// Formats this point in time according to a strftime-style pattern. The
+    /// supported specifiers are `%Y` (year), `%m` (zero-padded month), `%d`
+    /// (zero-padded day of the month), `%H`, `%M` and `%S` (zero-padded hour,
+    /// minute and second), `%B` and `%b` (full and abbreviated month name), `%A`
+    /// and `%a` (full and abbreviated weekday name), and `%%` (a literal percent
+    /// sign). Any other specifier, including a `%` at the end of the pattern, is
+    /// copied to the output verbatim. For example "%a, %d %b %Y %H:%M:%S"
+    /// produces the same output as `to_string_padded()`.
+    pub fn format(&self, pattern: &str) -> String {
+        let d = self.decompose();
+        let mut result = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('Y') => result.push_str(&d.year().to_string()),
+                Some('m') => result.push_str(&format!("{:02}", index_from_month(d.month()))),
+                Some('d') => result.push_str(&format!("{:02}", d.day_of_month())),
+                Some('H') => result.push_str(&format!("{:02}", d.hour())),
+                Some('M') => result.push_str(&format!("{:02}", d.minute())),
+                Some('S') => result.push_str(&format!("{:02}", d.second())),
+                Some('B') => result.push_str(month_string(d.month())),
+                Some('b') => result.push_str(month_abbrev_string(d.month())),
+                Some('A') => result.push_str(day_string(d.day_of_week())),
+                Some('a') => result.push_str(day_abbrev_string(d.day_of_week())),
+                Some('%') => result.push('%'),
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                }
+                None => result.push('%'),
+            }
+        }
+        result
+    }
+
+    /// Formats this point in time as an RFC 2822 date, as used in email and HTTP
+    /// headers, e.g. "Sun, 02 Feb 2020 02:25:40 +0000". The offset is always
+    /// "+0000" since `PostEpochTime` is in UTC.
+    pub fn to_rfc2822(&self) -> String {
+        format!("{} +0000", self.to_string_padded())
+    }
+
+    /// Formats this point in time the same way as the `Display` impl, except that
+    /// the day of the month is zero-padded to two digits, e.g.
+    /// "Sun, 02 Feb 2020 02:25:40".
+    pub fn to_string_padded(&self) -> String {
+        let d = self.decompose();
+        format!(
+            "{}, {:02} {} {} {:02}:{:02}:{:02}",
+            day_abbrev_string(d.day_of_week()),
+            d.day_of_month(),
+            month_abbrev_string(d.month()),
+            d.year(),
+            d.hour(),
+            d.minute(),
+            d.second()
+        )
+    }
+
+    /// Returns the instant of midnight at the start of the local day in the given
+    /// offset which contains this point in time. For example, 2020-02-02T20:00:00Z
+    /// is already 2020-02-03 at UTC+05:30, so its local midnight there is
+    /// 2020-02-02T18:30:00Z. Returns `None` if that instant is before the unix epoch.
+    pub fn local_midnight(&self, offset: FixedOffset) -> Option<PostEpochTime> {
+        let day = i128::from(seconds_in_day());
+        let offset = i128::from(offset.seconds());
+        let local = i128::from(self.seconds_since_epoch()) + offset;
+        let midnight = local.div_euclid(day) * day - offset;
+        u64::try_from(midnight).ok().map(|secs| PostEpochTime {
+            delta: Duration::from_secs(secs),
+        })
+    }
+
+    /// Formats the date in long form with the full day and month names, in the
+    /// US style, e.g. "Sunday, February 2, 2020".
+    pub fn to_long_date(&self) -> String {
+        let d = self.decompose();
+        format!(
+            "{}, {} {}, {}",
+            day_string(d.day_of_week()),
+            month_string(d.month()),
+            d.day_of_month(),
+            d.year()
+        )
+    }
+
+    /// Formats the date in long form as per `to_long_date`, followed by the time
+    /// of day, e.g. "Sunday, February 2, 2020 02:25:40".
+    pub fn to_long_date_with_time(&self) -> String {
+        format!(
+            "{} {:02}:{:02}:{:02}",
+            self.to_long_date(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+
+    /// Returns a view of this point in time in the local time of the given UTC
+    /// offset. Returns `None` if the local date/time would be before the unix
+    /// epoch, which can only happen for negative offsets on January 1, 1970.
+    pub fn with_offset(&self, offset: FixedOffset) -> Option<LocalDateTime> {
+        let local = if offset.seconds() < 0 {
+            self.delta.checked_sub(Duration::from_secs(u64::from(
+                offset.seconds().unsigned_abs(),
+            )))?
+        } else {
+            self.delta
+                .checked_add(Duration::from_secs(offset.seconds() as u64))?
+        };
+        Some(LocalDateTime {
+            utc: self.delta,
+            local: PostEpochTime { delta: local },
+            offset,
+        })
+    }
+
+    /// Same as `with_offset`, but taking the offset as a number of seconds east of
+    /// UTC (negative values are west of UTC). Also returns `None` if the offset is
+    /// a day or more in either direction.
+    pub fn with_offset_seconds(&self, offset_secs: i64) -> Option<LocalDateTime> {
+        let offset = FixedOffset::from_seconds(i32::try_from(offset_secs).ok()?)?;
+        self.with_offset(offset)
+    }
+
+    /// Returns a compact label with the abbreviated day of the week and the day of
+    /// the month, e.g. "Sun 2", for use in calendar cells.
+    pub fn cell_label(&self) -> String {
+        format!(
+            "{} {}",
+            day_abbrev_string(self.day_of_week()),
+            self.day_of_month()
+        )
+    }
+
+    /// Returns a view of this point in time whose `Display` impl shows only the
+    /// date, e.g. "2 Feb 2020".
+    pub fn date_only(&self) -> DateOnly<'_> {
+        DateOnly(self)
+    }
+
+    /// Returns the point in time exactly one day later, with the same time of day.
+    /// This panics if that point in time cannot be represented.
+    pub fn tomorrow(&self) -> PostEpochTime {
+        PostEpochTime {
+            delta: self.delta + Duration::from_secs(seconds_in_day()),
+        }
+    }
+
+    /// Returns the point in time exactly one day earlier, with the same time of
+    /// day. Returns `None` if that would be before the unix epoch, i.e. if this
+    /// point in time is on January 1, 1970.
+    pub fn yesterday(&self) -> Option<PostEpochTime> {
+        self.delta
+            .checked_sub(Duration::from_secs(seconds_in_day()))
+            .map(|delta| PostEpochTime { delta })
+    }
+
+    /// Returns the point in time `days` calendar days later (or earlier, if `days`
+    /// is negative), with the same time of day. This panics if that point in time
+    /// is before the unix epoch or cannot be represented.
+    pub fn add_days(&self, days: i64) -> PostEpochTime {
+        let delta = days
+            .unsigned_abs()
+            .checked_mul(seconds_in_day())
+            .map(Duration::from_secs)
+            .and_then(|offset| {
+                if days < 0 {
+                    self.delta.checked_sub(offset)
+                } else {
+                    self.delta.checked_add(offset)
+                }
+            });
+        PostEpochTime {
+            delta: delta.expect("add_days result out of range"),
+        }
+    }
+
+    /// Returns the point in time `months` calendar months later (or earlier, if
+    /// `months` is negative), with the same day of the month and time of day. If
+    /// the day of the month does not exist in the target month, it is clamped to
+    /// the last day of that month, so January 31 plus one month is February 28 (or
+    /// 29 in a leap year). This panics if that point in time is before the unix
+    /// epoch or cannot be represented.
+    pub fn add_months(&self, months: i64) -> PostEpochTime {
+        let d = self.decompose();
+        let total = i128::from(d.year()) * 12
+            + i128::from(index_from_month(d.month()) - 1)
+            + i128::from(months);
+        let year = u64::try_from(total.div_euclid(12)).expect("add_months result out of range");
+        let month = month_from_index(total.rem_euclid(12) as u64 + 1).expect("Month was in range");
+        let day = d.day_of_month().min(days_in_month(year, month));
+        let days = days_from_date(year, month, day).expect("add_months result out of range");
+        let secs = days
+            .checked_mul(seconds_in_day())
+            .and_then(|secs| secs.checked_add(self.second_in_day()))
+            .expect("add_months result out of range");
+        PostEpochTime {
+            delta: Duration::new(secs, self.delta.subsec_nanos()),
+        }
+    }
+
+    /// Returns the point in time `years` calendar years later (or earlier, if
+    /// `years` is negative), with the same month, day of the month and time of day.
+    /// February 29 is clamped to February 28 if the target year is not a leap
+    /// year. This panics if that point in time is before the unix epoch or cannot
+    /// be represented.
+    pub fn add_years(&self, years: i64) -> PostEpochTime {
+        self.add_months(
+            years
+                .checked_mul(12)
+                .expect("add_years result out of range"),
+        )
+    }
+
+    /// Returns the point in time `d` earlier than this one, or `None` if that would
+    /// be before the unix epoch.
+    pub fn checked_sub(&self, d: Duration) -> Option<PostEpochTime> {
+        self.delta
+            .checked_sub(d)
+            .map(|delta| PostEpochTime { delta })
+    }
+
+    /// Returns an iterator over the starts of each day from the day this point in
+    /// time falls on up to `end`, i.e. `start_of_day()` followed by each following
+    /// midnight, stopping before the first one that is not earlier than `end`. The
+    /// iterator is empty if `end` is not later than this point in time.
+    pub fn days_until(&self, end: &PostEpochTime) -> impl Iterator<Item = PostEpochTime> {
+        let end = end.delta;
+        let first = Some(self.start_of_day()).filter(|_| end > self.delta);
+        core::iter::successors(first, |t| {
+            t.delta
+                .checked_add(Duration::from_secs(seconds_in_day()))
+                .map(|delta| PostEpochTime { delta })
+        })
+        .take_while(move |t| t.delta < end)
+    }
+
+    /// Returns an iterator over `count` points in time, starting with this one and
+    /// advancing by `step` days each time, preserving the time of day. The iterator
+    /// ends early if a point in time would not be representable.
+    pub fn step_days(&self, step: u64, count: usize) -> impl Iterator<Item = PostEpochTime> {
+        let stride = step.checked_mul(seconds_in_day()).map(Duration::from_secs);
+        core::iter::successors(Some(self.delta), move |delta| {
+            stride.and_then(|stride| delta.checked_add(stride))
+        })
+        .take(count)
+        .map(|delta| PostEpochTime { delta })
+    }
+
+    fn year_split(&self) -> (u64, u64) {
+        split_year(self.days_since_epoch())
+    }
+
+    /// Computes all of the calendar fields of this point in time at once. Each of
+    /// the individual accessors such as `year()` and `month()` has to work out the
+    /// year and month from scratch, so when reading several fields it is cheaper
+    /// to call this once and read them from the returned `DecomposedTime`.
+    pub fn decompose(&self) -> DecomposedTime {
+        let (year, days) = self.year_split();
+        let (month, day) = split_month(year, days);
+        DecomposedTime {
+            year,
+            month,
+            day_of_month: day + 1,
+            day_of_year: days + 1,
+            day_of_week: self.day_of_week(),
+            second_in_day: self.second_in_day(),
+        }
+    }
+
+    /// Returns the year (e.g. 2020) this point in time falls on.
+    pub fn year(&self) -> u64 {
+        self.year_split().0
+    }
+
+    /// Returns the decade this point in time falls in, as the year rounded down to
+    /// a multiple of ten (e.g. 2020 for any year from 2020 to 2029).
+    pub fn decade(&self) -> u64 {
+        self.year() / 10 * 10
+    }
+
+    /// Returns the century this point in time falls in, computed as `year / 100 + 1`.
+    /// This follows the popular convention where centuries start on years that
+    /// are multiples of 100, so 1999 is in the 20th century and 2000 is in the
+    /// 21st. Note that under the strict convention (where there is no year zero
+    /// and centuries start on years ending in 01) 2000 would instead be the last
+    /// year of the 20th century.
+    pub fn century(&self) -> u64 {
+        self.year() / 100 + 1
+    }
+
+    /// Returns the day of the year for this point in time (1-indexed).
+    /// A return value of 1 indicates January 1, a value of 2 indicates January 2,
+    /// and so on. If the year is a leap year the largest returned value
+    /// would be 366, and for non-leap years it would be 365.
+    pub fn day_of_year(&self) -> u64 {
+        self.year_split().1 + 1
+    }
+
+    /// Returns the number of days remaining in the year after the day this point
+    /// in time falls on, so 0 on December 31 and 364 on January 1 of a non-leap
+    /// year.
+    pub fn days_remaining_in_year(&self) -> u64 {
+        days_in_year(self.year()) - self.day_of_year()
+    }
+
+    /// Formats the date as an ISO 8601 ordinal date, i.e. the year followed by the
+    /// zero-padded day of the year (e.g. "2020-033").
+    pub fn to_iso_ordinal(&self) -> String {
+        let (year, days) = self.year_split();
+        format!("{:04}-{:03}", year, days + 1)
+    }
+
+    // Returns the ISO 8601 week-numbering year and week number (1-53).
+    fn iso_week_split(&self) -> (u64, u64) {
+        let (year, days) = self.year_split();
+        let weekday = iso_weekday(self.day_of_week());
+        let week = (days + 1 + 10 - weekday) / 7;
+        if week < 1 {
+            (year - 1, iso_weeks_in_year(year - 1))
+        } else if week > iso_weeks_in_year(year) {
+            (year + 1, 1)
+        } else {
+            (year, week)
+        }
+    }
+
+    /// Returns the ISO 8601 week number (1-53). Weeks start on Monday, and week 1
+    /// is the week containing the first Thursday of the ISO year, so days near the
+    /// start or end of the year can belong to a week of the previous or next ISO
+    /// year; see `iso_week_year`.
+    pub fn iso_week(&self) -> u64 {
+        self.iso_week_split().1
+    }
+
+    /// Returns the ISO 8601 week-numbering year that `iso_week()` belongs to. This
+    /// is usually the same as `year()`, but differs for days near the start or end
+    /// of the year, e.g. January 1, 2021 is in week 53 of ISO year 2020.
+    pub fn iso_week_year(&self) -> u64 {
+        self.iso_week_split().0
+    }
+
+    /// Formats the date as an ISO 8601 week date, i.e. the ISO week-numbering
+    /// year, the week number, and the weekday from 1 (Monday) to 7 (Sunday), e.g.
+    /// "2020-W05-7". Note that the ISO year can differ from `year()` for days near
+    /// the start or end of the year.
+    pub fn to_iso_week_date(&self) -> String {
+        let (year, week) = self.iso_week_split();
+        format!(
+            "{:04}-W{:02}-{}",
+            year,
+            week,
+            iso_weekday(self.day_of_week())
+        )
+    }
+
+    /// Returns true if this point in time falls in an even-numbered ISO 8601 week,
+    /// as used by `to_iso_week_date`. Note that in ISO years with 53 weeks, week 53
+    /// and the following week 1 are both odd, so alternating-week schedules that
+    /// must never repeat should count weeks from a fixed start instead.
+    pub fn is_even_week(&self) -> bool {
+        self.iso_week() % 2 == 0
+    }
+
+    fn month_split(&self) -> (Month, u64) {
+        let (year, days) = self.year_split();
+        split_month(year, days)
+    }
+
+    /// Returns the month this point in time falls on.
+    pub fn month(&self) -> Month {
+        self.month_split().0
+    }
+
+    /// Returns the day of the month for this point in time (1-indexed).
+    /// A return value of 1 means it falls on the first of the month. The maximum
+    /// returned value will be 31.
+    pub fn day_of_month(&self) -> u64 {
+        self.month_split().1 + 1
+    }
+
+    /// Returns the day of the month with its English ordinal suffix, e.g. "2nd".
+    pub fn day_of_month_ordinal(&self) -> String {
+        let day = self.day_of_month();
+        format!("{}{}", day, ordinal_suffix(day))
+    }
+
+    /// Returns the number of complete days elapsed in the month before this point
+    /// in time (0-indexed). This is one less than `day_of_month()`.
+    pub fn days_elapsed_in_month(&self) -> u64 {
+        self.month_split().1
+    }
+
+    /// Returns the total number of days in the month this point in time falls on.
+    pub fn days_in_current_month(&self) -> u64 {
+        let (year, days) = self.year_split();
+        days_in_month(year, split_month(year, days).0)
+    }
+
+    /// Returns the number of days in the month before the one this point in time
+    /// falls on, rolling back into the previous year for January.
+    pub fn previous_month_length(&self) -> u64 {
+        let (year, days) = self.year_split();
+        match index_from_month(split_month(year, days).0) {
+            1 => days_in_month(year - 1, Month::December),
+            index => days_in_month(
+                year,
+                month_from_index(index - 1).expect("Month was in range"),
+            ),
+        }
+    }
+
+    /// Returns the number of days in the month after the one this point in time
+    /// falls on, rolling over into the next year for December.
+    pub fn next_month_length(&self) -> u64 {
+        let (year, days) = self.year_split();
+        match index_from_month(split_month(year, days).0) {
+            12 => days_in_month(year + 1, Month::January),
+            index => days_in_month(
+                year,
+                month_from_index(index + 1).expect("Month was in range"),
+            ),
+        }
+    }
+
+    /// Returns the point in time at midnight on the first day of this point in
+    /// time's month.
+    pub fn start_of_month(&self) -> PostEpochTime {
+        let days = self.days_since_epoch() - self.days_elapsed_in_month();
+        PostEpochTime {
+            delta: Duration::from_secs(days * seconds_in_day()),
+        }
+    }
+
+    /// Returns an iterator over the starts of each month from this point in
+    /// time's month up to `end`, i.e. `start_of_month()` followed by successive
+    /// applications of `start_of_next_month()`, stopping before the first one that
+    /// is not earlier than `end`. The first item may be earlier than this point in
+    /// time. If `end` is not later than `start_of_month()` the iterator is empty.
+    pub fn month_starts_until(&self, end: &PostEpochTime) -> impl Iterator<Item = PostEpochTime> {
+        let end = end.delta;
+        core::iter::successors(Some(self.start_of_month()), |t| {
+            t.checked_start_of_next_month()
+        })
+        .take_while(move |t| t.delta < end)
+    }
+
+    /// Returns the point in time at midnight on the first day of the following
+    /// month, rolling over into the next year after December. This panics if that
+    /// point in time cannot be represented.
+    pub fn start_of_next_month(&self) -> PostEpochTime {
+        self.checked_start_of_next_month()
+            .expect("start_of_next_month result out of range")
+    }
+
+    // Same as `start_of_next_month`, but returns `None` instead of panicking if the
+    // result cannot be represented.
+    fn checked_start_of_next_month(&self) -> Option<PostEpochTime> {
+        let (year, days) = self.year_split();
+        let (month, day_in_month) = split_month(year, days);
+        let days = self.days_since_epoch() - day_in_month + days_in_month(year, month);
+        let secs = days.checked_mul(seconds_in_day())?;
+        Some(PostEpochTime::from_seconds_since_epoch(secs))
+    }
+
+    /// Returns the number of days from the day this point in time falls on until
+    /// the last day of its month, so 0 on the last day and 2 on the third-to-last.
+    pub fn days_from_month_end(&self) -> u64 {
+        self.days_in_current_month() - self.day_of_month()
+    }
+
+    /// Returns the number of days remaining in the month after the day this point
+    /// in time falls on. This is the same as `days_from_month_end()`, named to
+    /// pair with `days_remaining_in_year()`.
+    pub fn days_remaining_in_month(&self) -> u64 {
+        self.days_from_month_end()
+    }
+
+    /// Returns the point in time at midnight on the day that is `n` days before the
+    /// last day of this point in time's month, i.e. the day for which
+    /// `days_from_month_end()` is `n`. Returns `None` if the month is not long
+    /// enough to have such a day, or if that point in time cannot be represented.
+    pub fn nth_from_month_end(&self, n: u64) -> Option<PostEpochTime> {
+        let remaining = self.days_from_month_end();
+        let last = self.days_in_current_month();
+        if n >= last {
+            return None;
+        }
+        let day = self.days_since_epoch() + remaining - n;
+        let secs = day.checked_mul(seconds_in_day())?;
+        Some(PostEpochTime::from_seconds_since_epoch(secs))
+    }
+
+    /// Returns true if this point in time falls on the first day of its month.
+    pub fn is_first_day_of_month(&self) -> bool {
+        self.days_elapsed_in_month() == 0
+    }
+
+    /// Returns true if this point in time falls on the last day of its month,
+    /// taking leap years into account for February.
+    pub fn is_last_day_of_month(&self) -> bool {
+        self.day_of_month() == self.days_in_current_month()
+    }
+
+    /// Returns true if this point in time falls on February 29.
+    pub fn is_leap_day(&self) -> bool {
+        let (month, days) = self.month_split();
+        matches!(month, Month::February) && days == 28
+    }
+
+    /// Returns which occurrence of its day of the week this point in time is within
+    /// its month, in the range 1..5 (inclusive). For example, the second Tuesday of
+    /// a month returns 2.
+    pub fn week_of_month(&self) -> u64 {
+        self.days_elapsed_in_month() / 7 + 1
+    }
+
+    /// Returns true if this point in time is the last occurrence of its day of the
+    /// week within its month, i.e. if the same day of the following week is in the
+    /// next month.
+    pub fn is_last_weekday_of_month(&self) -> bool {
+        self.days_from_month_end() < 7
+    }
+
+    /// Returns the number of times the `target` day of the week occurs in this
+    /// point in time's month. This will always be 4 or 5.
+    pub fn count_weekday_in_month(&self, target: Day) -> u64 {
+        let (year, days) = self.year_split();
+        let (month, day_in_month) = split_month(year, days);
+        let first = weekday_from_epoch_day(self.days_since_epoch() - day_in_month);
+        let offset = (index_from_day(target) + 7 - index_from_day(first)) % 7;
+        (days_in_month(year, month) - 1 - offset) / 7 + 1
+    }
+
+    /// Returns the number of times the `target` day of the week occurs from the day
+    /// this point in time falls on (inclusive) until the day `other` falls on
+    /// (exclusive). If `other` falls on an earlier day the count is negated, so
+    /// that `a.count_weekday_between(&b, d) == -b.count_weekday_between(&a, d)`.
+    /// This is computed directly rather than by visiting each day.
+    pub fn count_weekday_between(&self, other: &PostEpochTime, target: Day) -> i64 {
+        // The first epoch day that falls on the target day; epoch day 0 is a Thursday.
+        let first = (index_from_day(target) + 7 - index_from_day(Day::Thursday)) % 7;
+        // Number of target days in the epoch days 0..n
+        let count = |n: u64| ((n + 6 - first) / 7) as i64;
+        count(other.days_since_epoch()) - count(self.days_since_epoch())
+    }
+
+    /// Returns the calendar quarter this point in time falls in, in the range 1..4
+    /// (inclusive). Quarter 1 is January to March, quarter 2 is April to June, and
+    /// so on. This matches the quarter numbering used by `days_in_quarter`.
+    pub fn quarter(&self) -> u64 {
+        (index_from_month(self.month()) - 1) / 3 + 1
+    }
+
+    /// Returns the day of the quarter for this point in time (1-indexed).
+    /// A return value of 1 indicates the first day of January, April, July or
+    /// October. The maximum returned value will be 92.
+    pub fn day_of_quarter(&self) -> u64 {
+        let d = self.decompose();
+        let (year, index) = (d.year(), index_from_month(d.month()));
+        let first = index - (index - 1) % 3;
+        d.day_of_year() - days_before_month(year, first)
+    }
+
+    /// Returns the first and last days of the meteorological season that this
+    /// point in time falls in, as points in time at midnight at the start of
+    /// those days. Meteorological seasons are the three-month periods starting on
+    /// the first of March, June, September and December, so winter in the
+    /// northern hemisphere (and summer in the southern) spans the end of the year,
+    /// e.g. December 1, 2019 to February 29, 2020. These boundaries are the same
+    /// in both hemispheres; only the name of the season differs, so `hemisphere`
+    /// is accepted for symmetry with `season` but does not affect the result. For
+    /// the season that starts in December 1969, the start is clamped to the unix
+    /// epoch. Returns `None` if the end of the season cannot be represented, i.e.
+    /// for the last season before `PostEpochTime::MAX`.
+    pub fn season_bounds(&self, hemisphere: Hemisphere) -> Option<(PostEpochTime, PostEpochTime)> {
+        let _ = hemisphere;
+        let d = self.decompose();
+        let (year, month) = (d.year(), index_from_month(d.month()));
+        // Months since the start of the season (0-2)
+        let into_season = month % 3;
+        let (start_year, start_month) = if month < 3 {
+            (year - 1, 12)
+        } else {
+            (year, month - into_season)
+        };
+        let (end_year, end_month) = if start_month == 12 {
+            (start_year + 1, 2)
+        } else {
+            (start_year, start_month + 2)
+        };
+        let to_time = |year: u64, month: u64, day: Option<u64>| {
+            let month = month_from_index(month)?;
+            let day = day.unwrap_or_else(|| days_in_month(year, month));
+            let days = days_from_date(year, month, day).ok()?;
+            let secs = days.checked_mul(seconds_in_day())?;
+            Some(PostEpochTime::from_seconds_since_epoch(secs))
+        };
+        let start = if start_year < PostEpochTime::MIN_YEAR {
+            PostEpochTime::from_seconds_since_epoch(0)
+        } else {
+            to_time(start_year, start_month, Some(1))?
+        };
+        Some((start, to_time(end_year, end_month, None)?))
+    }
+
+    /// Returns the meteorological season that this point in time falls in, for
+    /// the given hemisphere. In the northern hemisphere December through February
+    /// is winter, March through May is spring, and so on; the southern hemisphere
+    /// has the opposite season for each month. See also `season_bounds`.
+    pub fn season(&self, hemisphere: Hemisphere) -> Season {
+        let northern = match self.month() {
+            Month::December | Month::January | Month::February => Season::Winter,
+            Month::March | Month::April | Month::May => Season::Spring,
+            Month::June | Month::July | Month::August => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+        };
+        match hemisphere {
+            Hemisphere::Northern => northern,
+            Hemisphere::Southern => northern.opposite(),
+        }
+    }
+
+    /// Returns the second within the day (0-indexed). This will be in the range
+    /// 0..86399 (inclusive).
+    pub fn second_in_day(&self) -> u64 {
+        self.delta.as_secs() % seconds_in_day()
+    }
+
+    /// Returns the fraction of the day that has elapsed at this point in time,
+    /// including the sub-second part. This will be in the range 0.0 (inclusive)
+    /// to 1.0 (exclusive), with noon being 0.5.
+    pub fn fraction_of_day(&self) -> f64 {
+        let seconds = self.second_in_day() as f64 + f64::from(self.delta.subsec_nanos()) / 1e9;
+        seconds / seconds_in_day() as f64
+    }
+
+    /// Returns the number of seconds since midnight. This is the same as
+    /// `second_in_day()`.
+    pub fn time_of_day_seconds(&self) -> u64 {
+        self.second_in_day()
+    }
+
+    /// Compares the time of day of this point in time with that of `other`,
+    /// ignoring the dates. Sub-second precision is taken into account.
+    pub fn compare_time_of_day(&self, other: &PostEpochTime) -> Ordering {
+        (self.second_in_day(), self.delta.subsec_nanos())
+            .cmp(&(other.second_in_day(), other.delta.subsec_nanos()))
+    }
+
+    /// Returns the hour within the day (0-indexed). This will be in the range
+    /// 0..23 (inclusive).
+    pub fn hour(&self) -> u64 {
+        self.second_in_day() / seconds_in_hour()
+    }
+
+    /// Returns the hour within the day (0-indexed). This is the same as `hour()`
+    /// and will be in the range 0..23 (inclusive).
+    pub fn hour_of_day(&self) -> u64 {
+        self.hour()
+    }
+
+    /// Returns the minute within the day (0-indexed). This will be in the range
+    /// 0..1439 (inclusive).
+    pub fn minute_of_day(&self) -> u64 {
+        self.second_in_day() / seconds_in_minute()
+    }
+
+    /// Returns the second within the hour (0-indexed). This will be in the range
+    /// 0..3599 (inclusive).
+    pub fn second_in_hour(&self) -> u64 {
+        self.second_in_day() % seconds_in_hour()
+    }
+
+    /// Returns the minute within the hour (0-indexed). This will be in the range
+    /// 0..59 (inclusive).
+    pub fn minute(&self) -> u64 {
+        self.second_in_hour() / seconds_in_minute()
+    }
+
+    /// Returns the hour on a 12-hour clock. This will be in the range 1..12
+    /// (inclusive), where both midnight and noon are 12.
+    pub fn hour_12(&self) -> u64 {
+        match self.hour() % 12 {
+            0 => 12,
+            h => h,
+        }
+    }
+
+    /// Returns whether this point in time is before or after noon, for use with
+    /// `hour_12()`.
+    pub fn meridiem(&self) -> Meridiem {
+        if self.hour() < 12 {
+            Meridiem::Am
+        } else {
+            Meridiem::Pm
+        }
+    }
+
+    /// Formats the time of day according to the given options, e.g. "14:25",
+    /// "14:25:40" or "2:25 PM".
+    pub fn format_time(&self, opts: TimeFormatOpts) -> String {
+        let mut result = if opts.twelve_hour {
+            format!("{}:{:02}", self.hour_12(), self.minute())
+        } else {
+            format!("{:02}:{:02}", self.hour(), self.minute())
+        };
+        if opts.seconds {
+            result.push_str(&format!(":{:02}", self.second()));
+        }
+        if opts.twelve_hour {
+            let marker = match (opts.meridiem, self.meridiem()) {
+                (MeridiemStyle::Upper, Meridiem::Am) => "AM",
+                (MeridiemStyle::Upper, Meridiem::Pm) => "PM",
+                (MeridiemStyle::Lower, Meridiem::Am) => "am",
+                (MeridiemStyle::Lower, Meridiem::Pm) => "pm",
+                (MeridiemStyle::Dotted, Meridiem::Am) => "a.m.",
+                (MeridiemStyle::Dotted, Meridiem::Pm) => "p.m.",
+            };
+            result.push(' ');
+            result.push_str(marker);
+        }
+        result
+    }
+
+    /// Returns the second within the minute (0-indexed). This will be in the range
+    /// 0..59 (inclusive).
+    pub fn second(&self) -> u64 {
+        self.delta.as_secs() % seconds_in_minute()
+    }
+
+    /// Returns the millisecond within the second. This will be in the range 0..999
+    /// (inclusive).
+    pub fn millisecond(&self) -> u32 {
+        self.delta.subsec_millis()
+    }
+
+    /// Returns the microsecond within the second. This will be in the range
+    /// 0..999,999 (inclusive).
+    pub fn microsecond(&self) -> u32 {
+        self.delta.subsec_micros()
+    }
+
+    /// Returns the nanosecond within the second. This will be in the range
+    /// 0..999,999,999 (inclusive).
+    pub fn nanosecond(&self) -> u32 {
+        self.delta.subsec_nanos()
+    }
+
+    /// Returns the second within the minute including the sub-second part, e.g.
+    /// 40.25 for a quarter of a second after 02:25:40. This will be in the range
+    /// 0.0 up to (but not including) 60.0, which makes it suitable for media
+    /// timecodes.
+    pub fn second_f64(&self) -> f64 {
+        self.second() as f64 + f64::from(self.delta.subsec_nanos()) / 1e9
+    }
+
+    /// Returns the Modified Julian Date, which is the number of days (including
+    /// the fraction of the current day) since midnight on November 17, 1858. This
+    /// is the Julian Date minus 2400000.5, so the unix epoch is MJD 40587.
+    pub fn modified_julian_date(&self) -> f64 {
+        MJD_OF_UNIX_EPOCH + self.delta.as_secs_f64() / seconds_in_day() as f64
+    }
+
+    /// Returns the Julian Date (JD), i.e. the number of days (including the
+    /// fraction of the current day) since noon on January 1, 4713 BCE in the
+    /// proleptic Julian calendar. Julian days start at noon, so the unix epoch is
+    /// 2440587.5. See `julian_day_number` for the integer day count.
+    pub fn julian_date(&self) -> f64 {
+        JD_OF_UNIX_EPOCH + self.delta.as_secs_f64() / seconds_in_day() as f64
+    }
+
+    /// Returns the integer Julian Day Number (JDN) of the day this point in time
+    /// falls on, which is the Julian Date at noon of that day, so the JDN of the
+    /// unix epoch is 2440588. This is the same as `jdn_from_ymd` for the date of
+    /// this point in time.
+    pub fn julian_day_number(&self) -> i64 {
+        // Days since the epoch are at most u64::MAX / 86400, which fits in an i64
+        self.days_since_epoch() as i64 + JDN_OF_UNIX_EPOCH
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl PostEpochTime {
+    /// Checks that the point in time `secs` seconds after the unix epoch survives
+    /// a round trip through each of the string formats and parsers in this crate,
+    /// as well as through its calendar components. Panics with a description of
+    /// the failure if not. The string formats only support four-digit years, so
+    /// for times after the year 9999 only the components are checked. This is
+    /// only available with the `testing` feature.
+    pub fn assert_roundtrip(secs: u64) {
+        let pet = PostEpochTime {
+            delta: Duration::from_secs(secs),
+        };
+        let rebuilt = PostEpochTime::from_components(
+            pet.year(),
+            pet.month(),
+            pet.day_of_month(),
+            pet.hour(),
+            pet.minute(),
+            pet.second(),
+        )
+        .unwrap_or_else(|e| panic!("from_components failed for {}: {}", secs, e));
+        assert_eq!(
+            rebuilt.seconds_since_epoch(),
+            secs,
+            "components of {}",
+            secs
+        );
+        if pet.year() > 9999 {
+            return;
+        }
+
+        let s = pet.to_rfc3339_millis();
+        let parsed = PostEpochTime::parse_rfc3339(&s)
+            .unwrap_or_else(|e| panic!("parse_rfc3339 failed for {:?}: {}", s, e));
+        assert_eq!(parsed.seconds_since_epoch(), secs, "{:?}", s);
+
+        let days = pet.days_since_epoch();
+        let s = pet.to_iso_ordinal();
+        let parsed = PostEpochTime::parse_iso_ordinal(&s)
+            .unwrap_or_else(|e| panic!("parse_iso_ordinal failed for {:?}: {}", s, e));
+        assert_eq!(parsed.days_since_epoch(), days, "{:?}", s);
+
+        let s = pet.to_iso_week_date();
+        let parsed = PostEpochTime::parse_iso_week_date(&s)
+            .unwrap_or_else(|e| panic!("parse_iso_week_date failed for {:?}: {}", s, e));
+        assert_eq!(parsed.days_since_epoch(), days, "{:?}", s);
+    }
+}
+
+impl fmt::Display for PostEpochTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.decompose();
+        write!(
+            f,
+            "{}, {} {} {} {:02}:{:02}:{:02}",
+            day_abbrev_string(d.day_of_week()),
+            d.day_of_month(),
+            month_abbrev_string(d.month()),
+            d.year(),
+            d.hour(),
+            d.minute(),
+            d.second()
+        )
+    }
+}
+
+/// A point in time relative to the unix epoch which, unlike `PostEpochTime`, can
+/// also be before the epoch. It is stored as a signed number of seconds since the
+/// epoch plus a non-negative number of nanoseconds, and covers roughly 292 billion
+/// years either side of it.
+///
+/// Years are numbered using the proleptic Gregorian calendar with astronomical
+/// year numbering, so the year before 1 is 0 and the year before that is -1. All
+/// of the date/time fields are in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EpochTime {
+    secs: i64,
+    nanos: u32,
+}
+
+impl EpochTime {
+    /// Create an `EpochTime` from a `SystemTime`, which may be before the unix
+    /// epoch. Returns an error if the `SystemTime` is too far from the epoch to be
+    /// represented.
+    #[cfg(feature = "std")]
+    pub fn from(st: &SystemTime) -> Result<Self, DateTimeError> {
+        let out_of_range = || DateTimeError::OutOfRange("time");
+        match st.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(d) => Ok(EpochTime {
+                secs: i64::try_from(d.as_secs()).map_err(|_| out_of_range())?,
+                nanos: d.subsec_nanos(),
+            }),
+            Err(e) => {
+                let d = e.duration();
+                let secs = i64::try_from(d.as_secs()).map_err(|_| out_of_range())?;
+                Ok(match d.subsec_nanos() {
+                    0 => EpochTime {
+                        secs: -secs,
+                        nanos: 0,
+                    },
+                    nanos => EpochTime {
+                        secs: (-secs).checked_sub(1).ok_or_else(out_of_range)?,
+                        nanos: 1_000_000_000 - nanos,
+                    },
+                })
+            }
+        }
+    }
+
+    /// Create an `EpochTime` from a (possibly negative) number of seconds since
+    /// the unix epoch.
+    pub fn from_seconds_since_epoch(secs: i64) -> Self {
+        EpochTime { secs, nanos: 0 }
+    }
+
+    /// Create an `EpochTime` from the given calendar date and time of day (in
+    /// UTC). Returns an error naming the offending field if the day is not valid
+    /// for the month and year, or if any of the time of day fields is out of range.
+    pub fn from_components(
+        year: i64,
+        month: Month,
+        day_of_month: u64,
+        hour: u64,
+        minute: u64,
+        second: u64,
+    ) -> Result<Self, DateTimeError> {
+        let out_of_range = || DateTimeError::OutOfRange("date");
+        // Shift the year into the first 400 year cycle after the epoch, which has
+        // the same calendar, and then shift the resulting day back.
+        let offset = year
+            .checked_sub(PostEpochTime::MIN_YEAR as i64)
+            .ok_or_else(out_of_range)?;
+        let cycle_year = PostEpochTime::MIN_YEAR + offset.rem_euclid(400) as u64;
+        let days = days_from_date(cycle_year, month, day_of_month)? as i64;
+        let time = seconds_from_time(hour, minute, second)? as i64;
+        let secs = offset
+            .div_euclid(400)
+            .checked_mul(DAYS_IN_400_YEARS as i64)
+            .and_then(|d| d.checked_add(days))
+            .and_then(|d| d.checked_mul(seconds_in_day() as i64))
+            .and_then(|s| s.checked_add(time))
+            .ok_or_else(out_of_range)?;
+        Ok(EpochTime { secs, nanos: 0 })
+    }
+
+    /// Converts this point in time to a `PostEpochTime`, or returns `None` if it
+    /// is before the unix epoch.
+    pub fn to_post_epoch(&self) -> Option<PostEpochTime> {
+        let secs = u64::try_from(self.secs).ok()?;
+        Some(PostEpochTime {
+            delta: Duration::new(secs, self.nanos),
+        })
+    }
+
+    /// Returns the number of whole seconds since the unix epoch, rounded down, so
+    /// that half a second before the epoch is -1.
+    pub fn seconds_since_epoch(&self) -> i64 {
+        self.secs
+    }
+
+    /// Returns the number of nanoseconds past `seconds_since_epoch()`. This is
+    /// always in the range 0..999,999,999 (inclusive), even before the epoch.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Returns the number of whole days since the unix epoch, rounded down, so
+    /// that any time on December 31, 1969 is -1.
+    pub fn days_since_epoch(&self) -> i64 {
+        self.secs.div_euclid(seconds_in_day() as i64)
+    }
+
+    // Returns the year, the year within the 400 year cycle after the epoch that
+    // has the same calendar, and the (0-indexed) day within the year.
+    fn year_split(&self) -> (i64, u64, u64) {
+        let days = self.days_since_epoch();
+        let cycles = days.div_euclid(DAYS_IN_400_YEARS as i64);
+        let (cycle_year, day) = split_year(days.rem_euclid(DAYS_IN_400_YEARS as i64) as u64);
+        let year = cycle_year as i64 + cycles * 400;
+        (year, cycle_year, day)
+    }
+
+    fn month_split(&self) -> (Month, u64) {
+        let (_, cycle_year, day) = self.year_split();
+        split_month(cycle_year, day)
+    }
+
+    /// Returns the year this point in time falls on, e.g. 1969, or 0 for 1 BCE.
+    pub fn year(&self) -> i64 {
+        self.year_split().0
+    }
+
+    /// Returns the month this point in time falls on.
+    pub fn month(&self) -> Month {
+        self.month_split().0
+    }
+
+    /// Returns the day of the month for this point in time (1-indexed).
+    pub fn day_of_month(&self) -> u64 {
+        self.month_split().1 + 1
+    }
+
+    /// Returns the day of the year for this point in time (1-indexed).
+    pub fn day_of_year(&self) -> u64 {
+        self.year_split().2 + 1
+    }
+
+    /// Returns the day of the week that this point in time falls on.
+    pub fn day_of_week(&self) -> Day {
+        // 400 years is a whole number of weeks, so this is the same weekday.
+        weekday_from_epoch_day(self.days_since_epoch().rem_euclid(DAYS_IN_400_YEARS as i64) as u64)
+    }
+
+    fn second_in_day(&self) -> u64 {
+        self.secs.rem_euclid(seconds_in_day() as i64) as u64
+    }
+
+    /// Returns the hour within the day (0-indexed). This will be in the range
+    /// 0..23 (inclusive).
+    pub fn hour(&self) -> u64 {
+        self.second_in_day() / seconds_in_hour()
+    }
+
+    /// Returns the minute within the hour (0-indexed). This will be in the range
+    /// 0..59 (inclusive).
+    pub fn minute(&self) -> u64 {
+        self.second_in_day() % seconds_in_hour() / seconds_in_minute()
+    }
+
+    /// Returns the second within the minute (0-indexed). This will be in the range
+    /// 0..59 (inclusive).
+    pub fn second(&self) -> u64 {
+        self.second_in_day() % seconds_in_minute()
+    }
+}
+
+/// Displays the time in the same format as `PostEpochTime`, e.g.
+/// "Wed, 31 Dec 1969 23:59:59".
+impl fmt::Display for EpochTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Split out the year and month once, rather than once per field
+        let (year, cycle_year, day) = self.year_split();
+        let (month, day_in_month) = split_month(cycle_year, day);
+        write!(
+            f,
+            "{}, {} {} {} {:02}:{:02}:{:02}",
+            day_abbrev_string(self.day_of_week()),
+            day_in_month + 1,
+            month_abbrev_string(month),
+            year,
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+/// The calendar fields of a `PostEpochTime`, computed once by
+/// `PostEpochTime::decompose`. The accessors return the same values as the
+/// accessors of the same name on `PostEpochTime`.
+#[derive(Debug, Clone, Copy)]
+pub struct DecomposedTime {
+    year: u64,
+    month: Month,
+    day_of_month: u64,
+    day_of_year: u64,
+    day_of_week: Day,
+    second_in_day: u64,
+}
+
+impl DecomposedTime {
+    /// Returns the year (e.g. 2020).
+    pub fn year(&self) -> u64 {
+        self.year
+    }
+
+    /// Returns the month.
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the day of the month (1-indexed).
+    pub fn day_of_month(&self) -> u64 {
+        self.day_of_month
+    }
+
+    /// Returns the day of the year (1-indexed).
+    pub fn day_of_year(&self) -> u64 {
+        self.day_of_year
+    }
+
+    /// Returns the day of the week.
+    pub fn day_of_week(&self) -> Day {
+        self.day_of_week
+    }
+
+    /// Returns the hour within the day, in the range 0..23 (inclusive).
+    pub fn hour(&self) -> u64 {
+        self.second_in_day / seconds_in_hour()
+    }
+
+    /// Returns the minute within the hour, in the range 0..59 (inclusive).
+    pub fn minute(&self) -> u64 {
+        self.second_in_day % seconds_in_hour() / seconds_in_minute()
+    }
+
+    /// Returns the second within the minute, in the range 0..59 (inclusive).
+    pub fn second(&self) -> u64 {
+        self.second_in_day % seconds_in_minute()
+    }
+}
+
+/// Identifies a single (UTC) day, for use as a key when grouping points in time
+/// by the day they fall on. Created by `PostEpochTime::day_key`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayKey(u64);
+
+impl DayKey {
+    /// Returns the number of days from the unix epoch to this day.
+    pub fn days_since_epoch(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the date of this day as a (year, month, day of month) tuple.
+    pub fn to_date(&self) -> (u64, Month, u64) {
+        let (year, days) = split_year(self.0);
+        let (month, days) = split_month(year, days);
+        (year, month, days + 1)
+    }
+}
+
+/// A fixed offset from UTC, such as UTC+05:30 or UTC-08:00. Positive offsets are
+/// east of UTC (i.e. local time is ahead of UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedOffset {
+    seconds: i32,
+}
+
+impl FixedOffset {
+    /// The zero offset, i.e. UTC itself.
+    pub const UTC: FixedOffset = FixedOffset { seconds: 0 };
+
+    /// Creates an offset of the given number of seconds east of UTC (negative
+    /// values are west of UTC). Returns `None` if the offset is a day or more in
+    /// either direction.
+    pub fn from_seconds(seconds: i32) -> Option<Self> {
+        if seconds.unsigned_abs() < seconds_in_day() as u32 {
+            Some(FixedOffset { seconds })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of seconds east of UTC for this offset.
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+/// Displays the offset in the "+HH:MM" form used by RFC 3339. Any seconds in the
+/// offset are omitted.
+impl fmt::Display for FixedOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.seconds < 0 { '-' } else { '+' };
+        let abs = u64::from(self.seconds.unsigned_abs());
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            sign,
+            abs / seconds_in_hour(),
+            abs % seconds_in_hour() / seconds_in_minute()
+        )
+    }
+}
+
+/// How sub-second precision is dropped when formatting with fewer fractional
+/// digits than are available. The default is to truncate, which matches most
+/// other systems.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum FractionRounding {
+    /// Drop the extra digits, so .123999 becomes .123.
+    #[default]
+    Truncate,
+    /// Round to the nearest value, with halves rounded up, so .123999 becomes
+    /// .124 and .1235 becomes .124.
+    HalfUp,
+}
+
+/// A meteorological season, as returned by `PostEpochTime::season`. The
+/// `Display` impl produces the name of the season, e.g. "Autumn".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// Returns the season on the other side of the year, e.g. summer for winter.
+    fn opposite(self) -> Season {
+        match self {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Season::Spring => write!(f, "Spring"),
+            Season::Summer => write!(f, "Summer"),
+            Season::Autumn => write!(f, "Autumn"),
+            Season::Winter => write!(f, "Winter"),
+        }
+    }
+}
+
+/// The hemisphere of the earth, which determines the season for a given month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// Whether a time of day is before noon (AM) or from noon onwards (PM). The
+/// `Display` impl produces "AM" or "PM".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meridiem {
+    Am,
+    Pm,
+}
+
+impl fmt::Display for Meridiem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Meridiem::Am => write!(f, "AM"),
+            Meridiem::Pm => write!(f, "PM"),
+        }
+    }
+}
+
+/// How the AM/PM marker is written when formatting a 12-hour time.
+#[derive(Debug, Clone, Copy)]
+pub enum MeridiemStyle {
+    /// "AM" and "PM".
+    Upper,
+    /// "am" and "pm".
+    Lower,
+    /// "a.m." and "p.m.".
+    Dotted,
+}
+
+/// Options controlling the output of `PostEpochTime::format_time`. The default
+/// is a 24-hour clock with seconds, e.g. "14:25:40".
+#[derive(Debug, Clone, Copy)]
+pub struct TimeFormatOpts {
+    /// Use a 12-hour clock with an AM/PM marker, e.g. "2:25 PM", instead of a
+    /// zero-padded 24-hour clock, e.g. "14:25".
+    pub twelve_hour: bool,
+    /// Include the seconds, e.g. "14:25:40".
+    pub seconds: bool,
+    /// The style of the AM/PM marker. Only used for a 12-hour clock.
+    pub meridiem: MeridiemStyle,
+}
+
+impl Default for TimeFormatOpts {
+    fn default() -> Self {
+        TimeFormatOpts {
+            twelve_hour: false,
+            seconds: true,
+            meridiem: MeridiemStyle::Upper,
+        }
+    }
+}
+
+/// A view of a `PostEpochTime` that displays only the date, without the time of
+/// day. Created by `PostEpochTime::date_only`.
+#[derive(Debug, Clone, Copy)]
+pub struct DateOnly<'a>(&'a PostEpochTime);
+
+impl fmt::Display for DateOnly<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.0.decompose();
+        write!(
+            f,
+            "{} {} {}",
+            d.day_of_month(),
+            month_abbrev_string(d.month()),
+            d.year()
+        )
+    }
+}
+
+/// A point in time viewed in the local time of a fixed UTC offset, created by
+/// `PostEpochTime::with_offset`. This is a separate type from `PostEpochTime` so
+/// that local date/time fields can't be mistaken for UTC ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalDateTime {
+    utc: Duration,
+    // The local wall-clock time, represented as if it were in UTC.
+    local: PostEpochTime,
+    offset: FixedOffset,
+}
+
+impl LocalDateTime {
+    /// Returns the UTC offset that this local time is viewed in.
+    pub fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+
+    /// Returns the same point in time as a (UTC) `PostEpochTime`.
+    pub fn to_utc(&self) -> PostEpochTime {
+        PostEpochTime { delta: self.utc }
+    }
+
+    /// Returns the local year.
+    pub fn year(&self) -> u64 {
+        self.local.year()
+    }
+
+    /// Returns the local month.
+    pub fn month(&self) -> Month {
+        self.local.month()
+    }
+
+    /// Returns the local day of the month (1-indexed).
+    pub fn day_of_month(&self) -> u64 {
+        self.local.day_of_month()
+    }
+
+    /// Returns the local day of the year (1-indexed).
+    pub fn day_of_year(&self) -> u64 {
+        self.local.day_of_year()
+    }
+
+    /// Returns the local day of the week.
+    pub fn day_of_week(&self) -> Day {
+        self.local.day_of_week()
+    }
+
+    /// Returns the local hour within the day, in the range 0..23 (inclusive).
+    pub fn hour(&self) -> u64 {
+        self.local.hour()
+    }
+
+    /// Returns the minute within the hour, in the range 0..59 (inclusive).
+    pub fn minute(&self) -> u64 {
+        self.local.minute()
+    }
+
+    /// Returns the second within the minute, in the range 0..59 (inclusive).
+    pub fn second(&self) -> u64 {
+        self.local.second()
+    }
+}
+
+/// Displays the local time in the same format as `PostEpochTime`, followed by
+/// the offset, e.g. "Sun, 2 Feb 2020 07:55:40 +05:30".
+impl fmt::Display for LocalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.local, self.offset)
+    }
+}
+
+/// A builder for a `PostEpochTime`, created by `PostEpochTime::builder`. Any
+/// fields that are not set default to the values at the unix epoch, i.e. the
+/// year defaults to 1970, the month to January, the day to 1, and the time to
+/// 00:00:00. So setting only the year and month gives midnight on the first of
+/// that month. The fields are validated when `build` is called, in the same way
+/// as `PostEpochTime::from_components`.
+#[derive(Debug, Clone, Copy)]
+pub struct PostEpochTimeBuilder {
+    year: u64,
+    month: Month,
+    day: u64,
+    hour: u64,
+    minute: u64,
+    second: u64,
+}
+
+impl Default for PostEpochTimeBuilder {
+    fn default() -> Self {
+        PostEpochTimeBuilder {
+            year: PostEpochTime::MIN_YEAR,
+            month: Month::January,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        }
+    }
+}
+
+impl PostEpochTimeBuilder {
+    /// Sets the year, e.g. 2020.
+    pub fn year(mut self, year: u64) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Sets the month.
+    pub fn month(mut self, month: Month) -> Self {
+        self.month = month;
+        self
+    }
+
+    /// Sets the day of the month (1-indexed).
+    pub fn day(mut self, day: u64) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Sets the hour within the day (0-23).
+    pub fn hour(mut self, hour: u64) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Sets the minute within the hour (0-59).
+    pub fn minute(mut self, minute: u64) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Sets the second within the minute (0-59).
+    pub fn second(mut self, second: u64) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Creates the `PostEpochTime`, or returns an error naming the first field
+    /// that is out of range.
+    pub fn build(&self) -> Result<PostEpochTime, DateTimeError> {
+        PostEpochTime::from_components(
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq<SystemTime> for PostEpochTime {
+    fn eq(&self, other: &SystemTime) -> bool {
+        match other.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(delta) => self.delta == delta,
+            Err(_) => false,
+        }
+    }
+}
+
+/// A `SystemTime` before the unix epoch compares as less than any `PostEpochTime`.
+#[cfg(feature = "std")]
+impl PartialOrd<SystemTime> for PostEpochTime {
+    fn partial_cmp(&self, other: &SystemTime) -> Option<Ordering> {
+        match other.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(delta) => self.delta.partial_cmp(&delta),
+            Err(_) => Some(Ordering::Greater),
+        }
+    }
+}
+
+/// Adds a duration to a point in time. This panics if the result cannot be
+/// represented.
+impl Add<Duration> for PostEpochTime {
+    type Output = PostEpochTime;
+
+    fn add(self, rhs: Duration) -> PostEpochTime {
+        PostEpochTime {
+            delta: self.delta + rhs,
+        }
+    }
+}
+
+/// Subtracts a duration from a point in time, saturating at the unix epoch if
+/// the result would be before it. Use `PostEpochTime::checked_sub` to detect
+/// that case instead.
+impl Sub<Duration> for PostEpochTime {
+    type Output = PostEpochTime;
+
+    fn sub(self, rhs: Duration) -> PostEpochTime {
+        PostEpochTime {
+            delta: self.delta.saturating_sub(rhs),
+        }
+    }
+}
+
+// The tests construct most of their inputs from a `SystemTime`, so they need std.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Parses an RFC 3339 timestamp, which must be valid.
+    fn at(s: &str) -> PostEpochTime {
+        PostEpochTime::parse_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn smoke_test() {
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::new(1580610340, 123);
+        let pet = PostEpochTime::from(&timestamp).unwrap();
+        assert_eq!(format!("{}", pet), "Sun, 2 Feb 2020 02:25:40".to_string());
+    }
+
+    #[test]
+    fn date_only() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(format!("{}", pet.date_only()), "2 Feb 2020");
+        let view = pet.date_only();
+        assert_eq!(format!("{:?}", view), format!("DateOnly({:?})", pet));
+    }
+
+    #[test]
+    fn padded_display() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.to_string_padded(), "Sun, 02 Feb 2020 02:25:40");
+        let pet = at("2020-02-16T15:00:00Z");
+        assert_eq!(pet.to_string_padded(), pet.to_string());
+    }
+
+    #[test]
+    fn long_date() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.to_long_date(), "Sunday, February 2, 2020");
+        assert_eq!(
+            pet.to_long_date_with_time(),
+            "Sunday, February 2, 2020 02:25:40"
+        );
+    }
+
+    #[test]
+    fn cell_label() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.cell_label(), "Sun 2");
+        assert_eq!(pet.add_days(10).cell_label(), "Wed 12");
+    }
+
+    #[test]
+    fn rfc2822() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.to_rfc2822(), "Sun, 02 Feb 2020 02:25:40 +0000");
+        let pet = PostEpochTime::from_seconds_since_epoch(0);
+        assert_eq!(pet.to_rfc2822(), "Thu, 01 Jan 1970 00:00:00 +0000");
+    }
+
+    #[test]
+    fn pre_epoch_error() {
+        let timestamp = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+        let err = PostEpochTime::from(&timestamp).err().unwrap();
+        assert!(matches!(err, DateTimeError::SystemTime(_)));
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn week_start() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        // Sun, 2 Feb 2020 00:00:00
+        assert_eq!(pet.seconds_at_start_of_week(Day::Sunday), 1580601600);
+        // Mon, 27 Jan 2020 00:00:00
+        assert_eq!(pet.seconds_at_start_of_week(Day::Monday), 1580083200);
+        let start = pet.start_of_week(Day::Monday);
+        assert_eq!(start.day_of_month(), 27);
+        assert_eq!(start.second_in_day(), 0);
+
+        let epoch = PostEpochTime::from(&SystemTime::UNIX_EPOCH).unwrap();
+        assert_eq!(epoch.seconds_at_start_of_week(Day::Thursday), 0);
+        assert_eq!(epoch.seconds_at_start_of_week(Day::Sunday), 0);
+
+        // The week of Mon, 27 Jan 2020 to Sun, 2 Feb 2020
+        let monday = at("2020-01-27T00:00:00Z");
+        assert_eq!(monday.weekday_index(Day::Monday), 0);
+        assert_eq!(monday.weekday_index(Day::Sunday), 1);
+        assert_eq!(pet.weekday_index(Day::Monday), 6);
+        assert_eq!(pet.weekday_index(Day::Sunday), 0);
+        assert_eq!(monday.add_days(5).weekday_index(Day::Monday), 5);
+        assert_eq!(monday.add_days(5).weekday_index(Day::Sunday), 6);
+    }
+
+    #[test]
+    fn compare_system_time() {
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::new(1580610340, 123);
+        let pet = PostEpochTime::from(&timestamp).unwrap();
+        assert!(pet == timestamp);
+        assert!(pet < timestamp + Duration::from_nanos(1));
+        assert!(pet > timestamp - Duration::from_nanos(1));
+        let pre_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+        assert!(pet != pre_epoch);
+        assert!(PostEpochTime::from(&SystemTime::UNIX_EPOCH).unwrap() > pre_epoch);
+    }
+
+    #[test]
+    fn duration_phrases() {
+        assert_eq!(duration_phrase(Duration::from_millis(500)), "0 seconds");
+        assert_eq!(duration_phrase(Duration::from_secs(1)), "1 second");
+        assert_eq!(duration_phrase(Duration::from_secs(59)), "59 seconds");
+        assert_eq!(duration_phrase(Duration::from_secs(60)), "1 minute");
+        assert_eq!(duration_phrase(Duration::from_secs(5400)), "1 hour");
+        assert_eq!(duration_phrase(Duration::from_secs(7200)), "2 hours");
+        assert_eq!(duration_phrase(Duration::from_secs(86400)), "1 day");
+        assert_eq!(duration_phrase(Duration::from_secs(86400 * 45)), "45 days");
+    }
+
+    #[test]
+    fn step_days() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        let days: Vec<_> = pet
+            .step_days(7, 5)
+            .map(|p| (p.month(), p.day_of_month(), p.second_in_day()))
+            .map(|(m, d, s)| (index_from_month(m), d, s))
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                (2, 2, 8740),
+                (2, 9, 8740),
+                (2, 16, 8740),
+                (2, 23, 8740),
+                (3, 1, 8740)
+            ]
+        );
+        assert_eq!(pet.step_days(u64::MAX, 3).count(), 1);
+    }
+
+    #[test]
+    fn parse_rfc3339() {
+        let pet = PostEpochTime::parse_rfc3339("2020-02-02T02:25:40Z").unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let pet = PostEpochTime::parse_rfc3339("2020-02-02T02:25:40.000000123Z").unwrap();
+        assert_eq!(pet.nanoseconds_since_epoch(), 1580610340000000123);
+        let pet = PostEpochTime::parse_rfc3339("2020-02-01T18:25:40-08:00").unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let pet = PostEpochTime::parse_rfc3339("2020-02-02T07:55:40+05:30").unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let pet = PostEpochTime::parse_rfc3339("1969-12-31T16:00:00-08:00").unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 0);
+
+        let check_err = |s: &str, position: usize, reason: &str| {
+            let err = PostEpochTime::parse_rfc3339(s).err().unwrap();
+            assert_eq!((err.position(), err.reason()), (position, reason), "{}", s);
+        };
+        check_err(
+            "1970-01-01T00:30:00+01:00",
+            0,
+            "time is before the unix epoch",
+        );
+        check_err("1969-12-31T23:59:59Z", 0, "time is before the unix epoch");
+        check_err("1969-12-30T23:59:59-08:00", 0, "year is before 1970");
+        check_err("2019-02-29T00:00:00Z", 8, "day out of range");
+        check_err("2019-13-01T00:00:00Z", 5, "month out of range");
+        check_err("2019-12-01T24:00:00Z", 11, "hour out of range");
+        check_err("2020-02-02T02:25:40+24:00", 20, "offset hour out of range");
+        check_err("2020-02-02T02:25:40", 19, "expected 'Z' or offset");
+        check_err("2020-02-02T02:25:40.Z", 20, "expected digit");
+        check_err(
+            "2020-02-02T02:25:40Z ",
+            20,
+            "unexpected trailing characters",
+        );
+        check_err("2020-02-02 02:25:40Z", 10, "expected 'T'");
+        check_err("2020-2-02T02:25:40Z", 6, "expected digit");
+        let err = PostEpochTime::parse_rfc3339("2020-02-0xT02:25:40Z")
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "expected digit at position 9");
+    }
+
+    #[test]
+    fn cumulative_days() {
+        const MARCH_2020: u64 = days_before_month(2020, 3);
+        assert_eq!(MARCH_2020, 60);
+        assert_eq!(days_before_month(2019, 3), 59);
+        assert_eq!(days_before_month(2020, 1), 0);
+        assert_eq!(days_before_month(2020, 2), 31);
+        assert_eq!(days_before_month(1900, 12), 334);
+        assert_eq!(days_before_month(2000, 12), 335);
+        for year in [1970, 2000, 2019] {
+            for index in 1..12 {
+                let month = month_from_index(index).unwrap();
+                assert_eq!(
+                    days_before_month(year, index) + days_in_month(year, month),
+                    days_before_month(year, index + 1)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn leap_day() {
+        let leap_day = at("2020-02-29T12:00:00Z");
+        assert!(leap_day.is_leap_day());
+        let before = at("2020-02-28T23:59:59Z");
+        assert!(!before.is_leap_day());
+        let after = at("2020-03-01T00:00:00Z");
+        assert!(!after.is_leap_day());
+    }
+
+    #[test]
+    fn first_weekday_of_month() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert!(matches!(pet.first_weekday_of_month(), Day::Saturday));
+        let pet = at("2020-03-31T23:59:59Z");
+        assert!(matches!(pet.first_weekday_of_month(), Day::Sunday));
+    }
+
+    #[test]
+    fn decompose_many() {
+        let secs = [0, 1580610340, 1582934400, 1577836799, 1580610341, 86399];
+        let fields = super::decompose_many(&secs);
+        assert_eq!(fields.len(), secs.len());
+        for (&secs, fields) in secs.iter().zip(fields.iter()) {
+            let pet =
+                PostEpochTime::from(&(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))).unwrap();
+            assert_eq!(fields.year, pet.year());
+            assert_eq!(
+                index_from_month(fields.month),
+                index_from_month(pet.month())
+            );
+            assert_eq!(fields.day_of_month, pet.day_of_month());
+            assert_eq!(fields.hour, pet.hour());
+            assert_eq!(fields.minute, pet.minute());
+            assert_eq!(fields.second, pet.second());
+        }
+    }
+
+    #[test]
+    fn midnights() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert_eq!(pet.previous_midnight().seconds_since_epoch(), 1580601600);
+        assert_eq!(
+            pet.previous_midnight().nanoseconds_since_epoch() % 1_000_000_000,
+            0
+        );
+        assert_eq!(pet.next_midnight().seconds_since_epoch(), 1580688000);
+        let midnight = pet.previous_midnight();
+        assert_eq!(
+            midnight.previous_midnight().seconds_since_epoch(),
+            1580601600
+        );
+        assert_eq!(midnight.next_midnight().seconds_since_epoch(), 1580688000);
+    }
+
+    #[test]
+    fn iso_weeks() {
+        assert_eq!(iso_weeks_in_year(2015), 53);
+        assert_eq!(iso_weeks_in_year(2019), 52);
+        assert_eq!(iso_weeks_in_year(2020), 53);
+        assert_eq!(iso_weeks_in_year(2021), 52);
+        assert_eq!(iso_weeks_in_year(2026), 53);
+        assert_eq!(iso_weeks_in_year(2032), 53);
+        assert_eq!(iso_weeks_in_year(2100), 52);
+        let total: u64 = (2000..2400).map(iso_weeks_in_year).sum();
+        assert_eq!(total * 7, 146097);
+        // u64::MAX is the same as 2015 modulo 400
+        assert_eq!(iso_weeks_in_year(u64::MAX), 53);
+    }
+
+    #[test]
+    fn format_time() {
+        let pet = at("2020-02-02T14:25:40Z");
+        assert_eq!(pet.format_time(TimeFormatOpts::default()), "14:25:40");
+        let no_seconds = TimeFormatOpts {
+            seconds: false,
+            ..Default::default()
+        };
+        assert_eq!(pet.format_time(no_seconds), "14:25");
+        let twelve_hour = TimeFormatOpts {
+            twelve_hour: true,
+            ..no_seconds
+        };
+        assert_eq!(pet.format_time(twelve_hour), "2:25 PM");
+        let dotted = TimeFormatOpts {
+            meridiem: MeridiemStyle::Dotted,
+            seconds: true,
+            ..twelve_hour
+        };
+        assert_eq!(pet.format_time(dotted), "2:25:40 p.m.");
+
+        let midnight = at("2020-02-02T00:05:00Z");
+        assert_eq!(midnight.format_time(twelve_hour), "12:05 AM");
+        assert_eq!(midnight.format_time(no_seconds), "00:05");
+        let noon = at("2020-02-02T12:00:00Z");
+        let lower = TimeFormatOpts {
+            meridiem: MeridiemStyle::Lower,
+            ..twelve_hour
+        };
+        assert_eq!(noon.format_time(lower), "12:00 pm");
+    }
+
+    #[test]
+    fn count_weekday_in_month() {
+        // February 2020 starts on a Saturday and has 29 days
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.count_weekday_in_month(Day::Saturday), 5);
+        assert_eq!(pet.count_weekday_in_month(Day::Sunday), 4);
+        assert_eq!(pet.count_weekday_in_month(Day::Friday), 4);
+        // February 2019 starts on a Friday and has 28 days
+        let pet = at("2019-02-28T00:00:00Z");
+        for day in [Day::Sunday, Day::Monday, Day::Friday, Day::Saturday] {
+            assert_eq!(pet.count_weekday_in_month(day), 4);
+        }
+        // January 2020 starts on a Wednesday and has 31 days
+        let pet = at("2020-01-15T00:00:00Z");
+        assert_eq!(pet.count_weekday_in_month(Day::Tuesday), 4);
+        assert_eq!(pet.count_weekday_in_month(Day::Wednesday), 5);
+        assert_eq!(pet.count_weekday_in_month(Day::Thursday), 5);
+        assert_eq!(pet.count_weekday_in_month(Day::Friday), 5);
+        assert_eq!(pet.count_weekday_in_month(Day::Saturday), 4);
+    }
+
+    #[test]
+    fn fraction_of_day() {
+        let noon = at("2020-02-02T12:00:00Z");
+        assert_eq!(noon.fraction_of_day(), 0.5);
+        let pet = at("2020-02-02T06:00:00.432Z");
+        assert!((pet.fraction_of_day() - 0.250005).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quarters() {
+        assert_eq!(days_in_quarter(2019, 1), Some(90));
+        assert_eq!(days_in_quarter(2020, 1), Some(91));
+        assert_eq!(days_in_quarter(2020, 2), Some(91));
+        assert_eq!(days_in_quarter(2020, 3), Some(92));
+        assert_eq!(days_in_quarter(2020, 4), Some(92));
+        assert_eq!(days_in_quarter(2020, 0), None);
+        assert_eq!(days_in_quarter(2020, 5), None);
+
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert_eq!(pet.day_of_quarter(), 33);
+        // 2020-03-31 is the last day of a leap-year Q1
+        let pet = PostEpochTime::parse_iso_ordinal("2020-091").unwrap();
+        assert_eq!(pet.day_of_quarter(), 91);
+        // 2020-04-01
+        let pet = PostEpochTime::parse_iso_ordinal("2020-092").unwrap();
+        assert_eq!(pet.day_of_quarter(), 1);
+        // 2019-12-31
+        let pet = PostEpochTime::parse_iso_ordinal("2019-365").unwrap();
+        assert_eq!(pet.day_of_quarter(), 92);
+    }
+
+    #[test]
+    fn iso_ordinal() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert_eq!(pet.to_iso_ordinal(), "2020-033");
+        let parsed = PostEpochTime::parse_iso_ordinal("2020-033").unwrap();
+        assert_eq!(parsed.days_since_epoch(), pet.days_since_epoch());
+        assert_eq!(parsed.second_in_day(), 0);
+        assert_eq!(
+            PostEpochTime::parse_iso_ordinal("2020-366")
+                .unwrap()
+                .to_iso_ordinal(),
+            "2020-366"
+        );
+        let check_err = |s: &str, position: usize, reason: &str| {
+            let err = PostEpochTime::parse_iso_ordinal(s).err().unwrap();
+            assert_eq!((err.position(), err.reason()), (position, reason), "{}", s);
+        };
+        check_err("2019-366", 5, "day of year out of range");
+        check_err("2019-000", 5, "day of year out of range");
+        check_err("1969-001", 0, "year is before 1970");
+        check_err("2020-33", 7, "expected digit");
+        check_err("2020-+33", 5, "expected digit");
+        check_err("2020-0333", 8, "unexpected trailing characters");
+    }
+
+    #[test]
+    fn leap_years_between() {
+        assert_eq!(super::leap_years_between(2020, 2020), 0);
+        assert_eq!(super::leap_years_between(2020, 2021), 1);
+        assert_eq!(super::leap_years_between(2021, 2024), 0);
+        assert_eq!(super::leap_years_between(2021, 2025), 1);
+        assert_eq!(super::leap_years_between(1900, 1901), 0);
+        assert_eq!(super::leap_years_between(2000, 2001), 1);
+        assert_eq!(super::leap_years_between(2025, 2020), 0);
+        assert_eq!(super::leap_years_between(0, 400), 97);
+        for start in [0, 1, 1896, 1970] {
+            for end in start..start + 500 {
+                let expected = (start..end).filter(|&y| is_leap_year(y)).count();
+                assert_eq!(super::leap_years_between(start, end), expected as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn local_midnight() {
+        let ist = FixedOffset::from_seconds(19800).unwrap();
+        let pst = FixedOffset::from_seconds(-28800).unwrap();
+        assert_eq!(ist.to_string(), "+05:30");
+        assert_eq!(pst.to_string(), "-08:00");
+        assert!(FixedOffset::from_seconds(86400).is_none());
+
+        let pet = at("2020-02-02T20:00:00Z");
+        let midnight = pet.local_midnight(ist).unwrap();
+        assert_eq!(midnight.seconds_since_epoch(), 1580668200); // 2020-02-02T18:30:00Z
+        let midnight = pet.local_midnight(pst).unwrap();
+        assert_eq!(midnight.seconds_since_epoch(), 1580630400); // 2020-02-02T08:00:00Z
+        let midnight = pet.local_midnight(FixedOffset::UTC).unwrap();
+        assert_eq!(midnight.seconds_since_epoch(), 1580601600); // 2020-02-02T00:00:00Z
+
+        let pet = at("1970-01-01T03:00:00Z");
+        assert!(pet.local_midnight(pst).is_none());
+        assert!(pet.local_midnight(ist).is_none());
+        assert_eq!(
+            pet.local_midnight(FixedOffset::UTC)
+                .unwrap()
+                .seconds_since_epoch(),
+            0
+        );
+        let pet = at("1970-01-02T03:00:00Z");
+        assert_eq!(
+            pet.local_midnight(pst).unwrap().seconds_since_epoch(),
+            28800
+        );
+    }
+
+    #[test]
+    fn is_within() {
+        let a = at("2020-02-02T02:25:40Z");
+        let b = at("2020-02-02T02:25:45.5Z");
+        assert!(a.is_within(&b, Duration::from_millis(5500)));
+        assert!(b.is_within(&a, Duration::from_millis(5500)));
+        assert!(!a.is_within(&b, Duration::from_secs(5)));
+        assert!(!b.is_within(&a, Duration::from_secs(5)));
+        assert!(a.is_within(&a, Duration::ZERO));
+    }
+
+    #[test]
+    fn weekdays_in_year() {
+        let mondays = super::weekdays_in_year(2020, Day::Monday);
+        assert_eq!(mondays.len(), 52);
+        assert!(matches!(mondays[0], (Month::January, 6)));
+        assert!(matches!(mondays[8], (Month::March, 2)));
+        assert!(matches!(mondays[51], (Month::December, 28)));
+        let wednesdays = super::weekdays_in_year(2020, Day::Wednesday);
+        assert_eq!(wednesdays.len(), 53);
+        assert!(matches!(wednesdays[0], (Month::January, 1)));
+        assert!(matches!(wednesdays[52], (Month::December, 30)));
+        let saturdays = super::weekdays_in_year(2000, Day::Saturday);
+        assert!(matches!(saturdays[0], (Month::January, 1)));
+        assert!(matches!(saturdays[8], (Month::February, 26)));
+        assert!(matches!(saturdays[9], (Month::March, 4)));
+        // u64::MAX is the same as 2015 modulo 400
+        assert_eq!(
+            super::weekdays_in_year(u64::MAX, Day::Monday),
+            super::weekdays_in_year(2015, Day::Monday)
+        );
+    }
+
+    #[test]
+    fn days_until_date() {
+        let pet = at("2020-02-02T23:59:59Z");
+        assert_eq!(pet.days_until_date(2020, Month::February, 2).unwrap(), 0);
+        assert_eq!(pet.days_until_date(2020, Month::February, 3).unwrap(), 1);
+        assert_eq!(pet.days_until_date(2020, Month::December, 25).unwrap(), 327);
+        assert_eq!(pet.days_until_date(2020, Month::January, 1).unwrap(), -32);
+        assert!(matches!(
+            pet.days_until_date(2019, Month::February, 29),
+            Err(DateTimeError::OutOfRange("day"))
+        ));
+        assert!(matches!(
+            pet.days_until_date(1969, Month::December, 31),
+            Err(DateTimeError::OutOfRange("year"))
+        ));
+    }
+
+    #[test]
+    fn local_date_time() {
+        let pet = at("2020-02-02T02:25:40Z");
+        let ist = FixedOffset::from_seconds(19800).unwrap();
+        let local = pet.with_offset(ist).unwrap();
+        assert_eq!(local.to_string(), "Sun, 2 Feb 2020 07:55:40 +05:30");
+        assert_eq!(
+            local.to_utc().seconds_since_epoch(),
+            pet.seconds_since_epoch()
+        );
+        assert_eq!(local.offset(), ist);
+
+        let pst = FixedOffset::from_seconds(-28800).unwrap();
+        let local = pet.with_offset(pst).unwrap();
+        assert_eq!(local.to_string(), "Sat, 1 Feb 2020 18:25:40 -08:00");
+        assert!(matches!(local.day_of_week(), Day::Saturday));
+        assert_eq!(local.day_of_year(), 32);
+        let copy = local;
+        assert_eq!(copy, local);
+        let views: std::collections::HashSet<LocalDateTime> =
+            [local, copy, pet.with_offset(ist).unwrap()]
+                .iter()
+                .copied()
+                .collect();
+        assert_eq!(views.len(), 2);
+        assert_ne!(pet.with_offset(ist), Some(local));
+
+        let epoch = PostEpochTime::from(&SystemTime::UNIX_EPOCH).unwrap();
+        assert!(epoch.with_offset(pst).is_none());
+    }
+
+    #[test]
+    fn from_components_overflow() {
+        let pet = PostEpochTime::from_components(2020, Month::February, 2, 2, 25, 40).unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let max = PostEpochTime::MAX_YEAR;
+        assert!(PostEpochTime::from_components(max, Month::January, 1, 0, 0, 0).is_ok());
+        assert!(matches!(
+            PostEpochTime::from_components(max, Month::December, 31, 0, 0, 0),
+            Err(DateTimeError::OutOfRange("date"))
+        ));
+        assert!(matches!(
+            PostEpochTime::from_components(max + 1, Month::January, 1, 0, 0, 0),
+            Err(DateTimeError::OutOfRange("year"))
+        ));
+        assert!(matches!(
+            PostEpochTime::from_components(u64::MAX, Month::January, 1, 0, 0, 0),
+            Err(DateTimeError::OutOfRange("year"))
+        ));
+    }
+
+    #[test]
+    fn tomorrow_yesterday() {
+        let pet = at("2020-02-29T02:25:40.5Z");
+        let tomorrow = pet.tomorrow();
+        assert_eq!(tomorrow.to_iso_ordinal(), "2020-061");
+        assert_eq!(tomorrow.second_in_day(), pet.second_in_day());
+        let yesterday = pet.yesterday().unwrap();
+        assert_eq!(yesterday.day_of_month(), 28);
+        assert_eq!(
+            yesterday.nanoseconds_since_epoch() % 1_000_000_000,
+            500_000_000
+        );
+
+        let pet = at("1970-01-01T23:59:59Z");
+        assert!(pet.yesterday().is_none());
+        assert_eq!(
+            pet.tomorrow().yesterday().unwrap().seconds_since_epoch(),
+            86399
+        );
+    }
+
+    #[test]
+    fn days_in_months() {
+        assert_eq!(
+            super::days_in_months(2020, Month::January, Month::December),
+            Some(366)
+        );
+        assert_eq!(
+            super::days_in_months(2019, Month::January, Month::December),
+            Some(365)
+        );
+        assert_eq!(
+            super::days_in_months(2020, Month::February, Month::February),
+            Some(29)
+        );
+        assert_eq!(
+            super::days_in_months(2019, Month::February, Month::March),
+            Some(59)
+        );
+        assert_eq!(
+            super::days_in_months(2020, Month::October, Month::December),
+            Some(92)
+        );
+        assert_eq!(
+            super::days_in_months(2020, Month::March, Month::February),
+            None
+        );
+    }
+
+    #[test]
+    fn day_key() {
+        use std::collections::HashMap;
+
+        let mut buckets = HashMap::new();
+        for s in [
+            "2020-02-02T02:25:40Z",
+            "2020-02-02T23:59:59Z",
+            "2020-02-03T00:00:00Z",
+            "2020-02-02T00:00:00Z",
+        ] {
+            let pet = at(s);
+            *buckets.entry(pet.day_key()).or_insert(0) += 1;
+        }
+        assert_eq!(buckets.len(), 2);
+        let key = at("2020-02-02T12:00:00Z").day_key();
+        assert_eq!(buckets[&key], 3);
+        assert!(matches!(key.to_date(), (2020, Month::February, 2)));
+        assert_eq!(key.days_since_epoch(), 18294);
+    }
+
+    #[test]
+    fn season_bounds() {
+        let check = |s: &str, start: &str, end: &str| {
+            let (first, last) = at(s).season_bounds(Hemisphere::Northern).unwrap();
+            assert_eq!(
+                at(s).season_bounds(Hemisphere::Southern),
+                Some((first, last))
+            );
+            assert_eq!(first.to_iso_ordinal(), start, "{}", s);
+            assert_eq!(last.to_iso_ordinal(), end, "{}", s);
+            assert_eq!(first.second_in_day(), 0);
+            assert_eq!(last.second_in_day(), 0);
+        };
+        // Winter spanning the year boundary, with a leap February
+        check("2020-02-02T02:25:40Z", "2019-335", "2020-060");
+        check("2019-12-01T00:00:00Z", "2019-335", "2020-060");
+        check("2020-12-31T23:59:59Z", "2020-336", "2021-059");
+        // Spring, Summer, Autumn
+        check("2020-03-01T00:00:00Z", "2020-061", "2020-152");
+        check("2020-08-31T23:59:59Z", "2020-153", "2020-244");
+        check("2020-10-15T12:00:00Z", "2020-245", "2020-335");
+        // Clamped to the epoch
+        check("1970-01-15T00:00:00Z", "1970-001", "1970-059");
+        // The season containing MAX ends after it
+        assert_eq!(PostEpochTime::MAX.season_bounds(Hemisphere::Northern), None);
+    }
+
+    #[test]
+    fn parse_us_short() {
+        let pet = PostEpochTime::parse_us_short("02/02/20", 70).unwrap();
+        assert_eq!(pet.to_iso_ordinal(), "2020-033");
+        let pet = PostEpochTime::parse_us_short("2/2/20", 70).unwrap();
+        assert_eq!(pet.to_iso_ordinal(), "2020-033");
+        let pet = PostEpochTime::parse_us_short("12/31/69", 70).unwrap();
+        assert_eq!(pet.year(), 2069);
+        let pet = PostEpochTime::parse_us_short("01/01/70", 70).unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 0);
+        let pet = PostEpochTime::parse_us_short("01/01/99", 100).unwrap();
+        assert_eq!(pet.year(), 2099);
+
+        let check_err = |s: &str, pivot: u64, position: usize, reason: &str| {
+            let err = PostEpochTime::parse_us_short(s, pivot).err().unwrap();
+            assert_eq!((err.position(), err.reason()), (position, reason), "{}", s);
+        };
+        check_err("12/31/69", 50, 6, "year is before 1970");
+        check_err("02/29/19", 50, 3, "day out of range");
+        check_err("13/01/20", 50, 0, "month out of range");
+        check_err("02-02-20", 50, 2, "expected '/'");
+        check_err("02/02/2020", 50, 8, "unexpected trailing characters");
+        check_err("02/02/2", 50, 7, "expected digit");
+    }
+
+    #[test]
+    fn seconds_between() {
+        let start = at("2020-02-02T02:25:40.9Z");
+        let end = at("2020-02-02T03:25:40.2Z");
+        assert_eq!(start.seconds_between(&end), 3599);
+        assert_eq!(end.seconds_between(&start), 0);
+        assert_eq!(start.seconds_between(&start), 0);
+    }
+
+    #[test]
+    fn u8_conversions() {
+        for value in 0..=255 {
+            match Day::from_u8(value) {
+                Some(day) => assert_eq!(day.as_u8(), value),
+                None => assert!(value > 6),
+            }
+            match Month::from_u8(value) {
+                Some(month) => assert_eq!(month.as_u8(), value),
+                None => assert!(value == 0 || value > 12),
+            }
+        }
+        assert_eq!(Day::Sunday.as_u8(), 0);
+        assert_eq!(Day::Saturday.as_u8(), 6);
+        assert_eq!(Month::January.as_u8(), 1);
+        assert_eq!(Month::December.as_u8(), 12);
+    }
+
+    #[test]
+    fn decade_century() {
+        let check = |s: &str, decade: u64, century: u64| {
+            let pet = at(s);
+            assert_eq!((pet.decade(), pet.century()), (decade, century), "{}", s);
+        };
+        check("1999-12-31T23:59:59Z", 1990, 20);
+        check("2000-01-01T00:00:00Z", 2000, 21);
+        check("2020-02-02T02:25:40Z", 2020, 21);
+        check("2029-12-31T23:59:59Z", 2020, 21);
+    }
+
+    #[test]
+    fn rfc3339_millis() {
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert_eq!(pet.to_rfc3339_millis(), "2020-02-02T02:25:40.000Z");
+        let pet = at("2020-02-02T02:25:40.123999Z");
+        assert_eq!(pet.to_rfc3339_millis(), "2020-02-02T02:25:40.123Z");
+        let parsed = PostEpochTime::parse_rfc3339(&pet.to_rfc3339_millis()).unwrap();
+        assert_eq!(
+            parsed.milliseconds_since_epoch(),
+            pet.milliseconds_since_epoch()
+        );
+    }
+
+    #[test]
+    fn builder() {
+        let pet = PostEpochTime::builder()
+            .year(2020)
+            .month(Month::February)
+            .day(2)
+            .hour(2)
+            .minute(25)
+            .second(40)
+            .build()
+            .unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let pet = PostEpochTime::builder().build().unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 0);
+        let pet = PostEpochTime::builder()
+            .year(2020)
+            .month(Month::March)
+            .build()
+            .unwrap();
+        assert_eq!(pet.to_rfc3339_millis(), "2020-03-01T00:00:00.000Z");
+        let builder = PostEpochTime::builder().year(2019).month(Month::February);
+        assert!(matches!(
+            builder.day(29).build(),
+            Err(DateTimeError::OutOfRange("day"))
+        ));
+        assert!(matches!(
+            builder.minute(60).build(),
+            Err(DateTimeError::OutOfRange("minute"))
+        ));
+        assert!(matches!(
+            builder.hour(24).build(),
+            Err(DateTimeError::OutOfRange("hour"))
+        ));
+        assert!(matches!(
+            PostEpochTime::builder().year(1969).build(),
+            Err(DateTimeError::OutOfRange("year"))
+        ));
+    }
+
+    #[test]
+    fn iso_week_date() {
+        let check = |s: &str, expected: &str| {
+            let pet = at(s);
+            assert_eq!(pet.to_iso_week_date(), expected, "{}", s);
+            let parsed = PostEpochTime::parse_iso_week_date(expected).unwrap();
+            assert_eq!(parsed.days_since_epoch(), pet.days_since_epoch(), "{}", s);
+        };
+        check("2020-02-02T02:25:40Z", "2020-W05-7");
+        check("1970-01-01T00:00:00Z", "1970-W01-4");
+        check("2021-01-01T00:00:00Z", "2020-W53-5");
+        check("2021-01-04T00:00:00Z", "2021-W01-1");
+        check("2019-12-30T00:00:00Z", "2020-W01-1");
+        check("2020-12-31T00:00:00Z", "2020-W53-4");
+        check("2008-12-29T00:00:00Z", "2009-W01-1");
+        check("2010-01-03T00:00:00Z", "2009-W53-7");
+
+        let check_err = |s: &str, position: usize, reason: &str| {
+            let err = PostEpochTime::parse_iso_week_date(s).err().unwrap();
+            assert_eq!((err.position(), err.reason()), (position, reason), "{}", s);
+        };
+        check_err("2021-W53-1", 6, "week out of range");
+        check_err("2020-W00-1", 6, "week out of range");
+        check_err("2020-W05-8", 9, "weekday out of range");
+        check_err("1970-W01-3", 0, "time is before the unix epoch");
+        check_err("1969-W52-1", 0, "year is before 1970");
+        check_err("2020-05-7", 5, "expected 'W'");
+    }
+
+    #[test]
+    fn weekday_from_epoch_day() {
+        let check = |day_index: u64, expected: Day| {
+            let day = super::weekday_from_epoch_day(day_index);
+            assert_eq!(
+                index_from_day(day),
+                index_from_day(expected),
+                "{}",
+                day_index
+            );
+        };
+        check(0, Day::Thursday);
+        check(1, Day::Friday);
+        check(2, Day::Saturday);
+        check(3, Day::Sunday);
+        check(4, Day::Monday);
+        check(5, Day::Tuesday);
+        check(6, Day::Wednesday);
+        check(7, Day::Thursday);
+        check(18294, Day::Sunday); // 2020-02-02
+        check(100000, Day::Tuesday); // 2243-10-17
+        check(u64::MAX, Day::Friday);
+    }
+
+    #[test]
+    fn from_year_ordinal_second() {
+        let pet = PostEpochTime::from_year_ordinal_second(2020, 33, 8740).unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        assert_eq!(
+            (pet.year(), pet.day_of_year(), pet.second_in_day()),
+            (2020, 33, 8740)
+        );
+        assert!(PostEpochTime::from_year_ordinal_second(2020, 366, 86399).is_ok());
+        assert!(matches!(
+            PostEpochTime::from_year_ordinal_second(2019, 366, 0),
+            Err(DateTimeError::OutOfRange("day of year"))
+        ));
+        assert!(matches!(
+            PostEpochTime::from_year_ordinal_second(2019, 1, 86400),
+            Err(DateTimeError::OutOfRange("second of day"))
+        ));
+        assert!(matches!(
+            PostEpochTime::from_year_ordinal_second(1969, 365, 0),
+            Err(DateTimeError::OutOfRange("year"))
+        ));
+    }
+
+    #[test]
+    fn start_of_next_month() {
+        let check = |s: &str, expected: &str| {
+            let next = at(s).start_of_next_month();
+            assert_eq!(next.to_rfc3339_millis(), expected, "{}", s);
+        };
+        check("2020-02-02T02:25:40Z", "2020-03-01T00:00:00.000Z");
+        check("2020-02-29T23:59:59Z", "2020-03-01T00:00:00.000Z");
+        check("2019-02-01T00:00:00Z", "2019-03-01T00:00:00.000Z");
+        check("2020-12-31T23:59:59.999Z", "2021-01-01T00:00:00.000Z");
+        check("2020-12-01T00:00:00Z", "2021-01-01T00:00:00.000Z");
+        check("2020-01-31T12:00:00Z", "2020-02-01T00:00:00.000Z");
+        assert!(PostEpochTime::MAX.checked_start_of_next_month().is_none());
+    }
+
+    #[test]
+    fn compare_time_of_day() {
+        let a = at("2020-02-02T09:00:00Z");
+        let b = at("1999-12-31T08:59:59.999Z");
+        let c = at("2030-06-15T09:00:00Z");
+        assert_eq!(a.compare_time_of_day(&b), Ordering::Greater);
+        assert_eq!(b.compare_time_of_day(&a), Ordering::Less);
+        assert_eq!(a.compare_time_of_day(&c), Ordering::Equal);
+        assert_eq!(a.time_of_day_seconds(), 32400);
+    }
+
+    #[test]
+    fn roundtrips() {
+        // Either side of every midnight from 1970 through to 2100, which covers all
+        // of the month and year boundaries including leap days.
+        let end = at("2100-01-01T00:00:00Z");
+        for day in 0..=end.days_since_epoch() {
+            let midnight = day * seconds_in_day();
+            PostEpochTime::assert_roundtrip(midnight);
+            PostEpochTime::assert_roundtrip(midnight + seconds_in_day() - 1);
+        }
+        // Far-future times, including the end of the four-digit years
+        for s in [
+            "2400-02-29T12:34:56Z",
+            "9999-12-31T23:59:59Z",
+            "4321-06-15T00:00:01Z",
+        ] {
+            let pet = at(s);
+            PostEpochTime::assert_roundtrip(pet.seconds_since_epoch());
+        }
+        PostEpochTime::assert_roundtrip(253402300800); // 10000-01-01T00:00:00Z
+        PostEpochTime::assert_roundtrip(1u64 << 40);
+    }
+
+    #[test]
+    fn max() {
+        let max = PostEpochTime::MAX;
+        assert_eq!(max.year(), PostEpochTime::MAX_YEAR);
+        assert_eq!(max.day_of_year(), 313);
+        assert_eq!(max.seconds_since_epoch(), u64::MAX);
+        assert!(matches!(max.month(), Month::November));
+    }
+
+    #[test]
+    fn components() {
+        let (year, month, day, hour, minute, second) = components_of_epoch_secs(1580657696);
+        assert_eq!((year, day, hour, minute, second), (2020, 2, 15, 34, 56));
+        assert!(matches!(month, Month::February));
+        let (year, month, day, ..) = components_of_epoch_secs(0);
+        assert_eq!((year, index_from_month(month), day), (1970, 1, 1));
+    }
+
+    #[test]
+    fn humanize() {
+        let from_secs = |secs| PostEpochTime {
+            delta: Duration::from_secs(secs),
+        };
+        let now = from_secs(100_000);
+        let zero = Duration::ZERO;
+        assert_eq!(now.humanize_relative_to(&now, zero), "just now");
+        assert_eq!(
+            from_secs(100_030).humanize_relative_to(&now, Duration::from_secs(30)),
+            "just now"
+        );
+        assert_eq!(
+            from_secs(99_970).humanize_relative_to(&now, Duration::from_secs(30)),
+            "just now"
+        );
+        assert_eq!(
+            from_secs(100_031).humanize_relative_to(&now, Duration::from_secs(30)),
+            "in 31 seconds"
+        );
+        assert_eq!(
+            from_secs(100_000 - 7200).humanize_relative_to(&now, zero),
+            "2 hours ago"
+        );
+        assert_eq!(
+            from_secs(100_000 + 86400).humanize_relative_to(&now, zero),
+            "in 1 day"
+        );
+        let half = PostEpochTime {
+            delta: Duration::from_millis(100_000_500),
+        };
+        assert_eq!(half.humanize_relative_to(&now, zero), "just now");
+
+        let now = at("2020-02-02T02:25:40Z");
+        let check = |s, past, future| {
+            let other = at(s);
+            assert_eq!(other.humanize_relative_to(&now, zero), past, "{}", s);
+            assert_eq!(now.humanize_relative_to(&other, zero), future, "{}", s);
+        };
+        check("2020-02-02T02:24:55Z", "45 seconds ago", "in 45 seconds");
+        check("2020-02-02T00:55:40Z", "1 hour ago", "in 1 hour");
+        check("2020-01-31T02:25:40Z", "2 days ago", "in 2 days");
+        check("2020-01-02T02:25:41Z", "30 days ago", "in 30 days");
+        check("2020-01-02T02:25:40Z", "1 month ago", "in 1 month");
+        check("2019-01-02T02:25:40Z", "1 year ago", "in 1 year");
+        check("2018-12-02T02:25:40Z", "1 year ago", "in 1 year");
+        check("2017-02-03T00:00:00Z", "2 years ago", "in 2 years");
+        assert_eq!(
+            at("2020-03-01T00:00:00Z").humanize_relative_to(&at("2020-01-31T00:00:00Z"), zero),
+            "in 1 month"
+        );
+    }
+
+    #[test]
+    fn headers() {
+        assert_eq!(
+            weekday_headers(Day::Monday, true),
+            ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+        );
+        assert_eq!(
+            weekday_headers(Day::Sunday, false)[..2],
+            ["Sunday", "Monday"]
+        );
+        assert!(matches!(Day::Saturday.next(), Day::Sunday));
+    }
+
+    #[test]
+    fn fractional_second() {
+        let pet = PostEpochTime {
+            delta: Duration::from_millis(1580610340250),
+        };
+        assert_eq!(pet.second_f64(), 40.25);
+    }
+
+    #[test]
+    fn mjd() {
+        let epoch = PostEpochTime::from_mjd(40587.0).unwrap();
+        assert_eq!(epoch.seconds_since_epoch(), 0);
+        assert_eq!(epoch.modified_julian_date(), 40587.0);
+        // J2000.0 is 2000-01-01T12:00:00, which is MJD 51544.5
+        let j2000 = PostEpochTime::from_mjd(51544.5).unwrap();
+        assert_eq!(j2000.to_rfc3339_millis(), "2000-01-01T12:00:00.000Z");
+        assert_eq!(j2000.modified_julian_date(), 51544.5);
+        assert!(PostEpochTime::from_mjd(40586.9).is_err());
+        assert!(PostEpochTime::from_mjd(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn month_ends() {
+        assert!(at("2020-02-01T12:00:00Z").is_first_day_of_month());
+        assert!(!at("2020-02-02T00:00:00Z").is_first_day_of_month());
+        assert!(at("2020-02-29T23:59:59Z").is_last_day_of_month());
+        assert!(!at("2020-02-28T23:59:59Z").is_last_day_of_month());
+        assert!(at("2021-02-28T00:00:00Z").is_last_day_of_month());
+        assert!(at("2021-12-31T00:00:00Z").is_last_day_of_month());
+    }
+
+    #[test]
+    fn clamp_time() {
+        let (open, close) = (9 * 3600, 17 * 3600);
+        let clamp = |s| at(s).clamp_time_of_day(open, close).to_rfc3339_millis();
+        assert_eq!(clamp("2020-02-02T08:15:00Z"), "2020-02-02T09:00:00.000Z");
+        assert_eq!(clamp("2020-02-02T18:30:00Z"), "2020-02-02T17:00:00.000Z");
+        assert_eq!(
+            clamp("2020-02-02T12:34:56.789Z"),
+            "2020-02-02T12:34:56.789Z"
+        );
+        assert_eq!(
+            clamp("2020-02-02T17:00:00.500Z"),
+            "2020-02-02T17:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn week_parity() {
+        assert!(!at("2020-02-02T00:00:00Z").is_even_week()); // 2020-W05-7
+        assert!(at("2020-02-03T00:00:00Z").is_even_week()); // 2020-W06-1
+        assert!(!at("2021-01-03T00:00:00Z").is_even_week()); // 2020-W53-7
+        assert!(!at("2021-01-04T00:00:00Z").is_even_week()); // 2021-W01-1
+    }
+
+    #[test]
+    fn age() {
+        let birth = at("2020-02-02T23:00:00Z");
+        assert_eq!(birth.age_in_days(&at("2020-02-02T23:59:59Z")), 0);
+        assert_eq!(birth.age_in_days(&at("2020-02-03T01:00:00Z")), 1);
+        assert_eq!(birth.age_in_days(&at("2021-02-02T00:00:00Z")), 366);
+        assert_eq!(birth.age_in_days(&at("2020-01-31T12:00:00Z")), -2);
+    }
+
+    #[test]
+    fn solstices() {
+        let dates = approximate_solstice_equinox(2020);
+        let dates: Vec<(u64, u64)> = dates
+            .iter()
+            .map(|&(m, d)| (index_from_month(m), d))
+            .collect();
+        assert_eq!(dates, [(3, 20), (6, 21), (9, 22), (12, 21)]);
+    }
+
+    #[test]
+    fn overlap() {
+        let from_secs = |secs| PostEpochTime {
+            delta: Duration::from_secs(secs),
+        };
+        let (t0, t10, t20, t30) = (from_secs(0), from_secs(10), from_secs(20), from_secs(30));
+        assert_eq!(
+            range_overlap((&t0, &t20), (&t10, &t30)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            range_overlap((&t10, &t30), (&t0, &t20)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            range_overlap((&t0, &t30), (&t10, &t20)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(range_overlap((&t0, &t10), (&t10, &t20)), None);
+        assert_eq!(range_overlap((&t0, &t10), (&t20, &t30)), None);
+        assert_eq!(range_overlap((&t20, &t10), (&t0, &t30)), None);
+    }
+
+    #[test]
+    fn hms() {
+        assert_eq!(format_hms(Duration::from_secs(3723)), "01:02:03");
+        assert_eq!(format_hms(Duration::from_secs(27 * 3600)), "27:00:00");
+        assert_eq!(format_hms(Duration::from_millis(999)), "00:00:00");
+        assert_eq!(format_hms(Duration::from_secs(360_000)), "100:00:00");
+    }
+
+    #[test]
+    fn weekday_between() {
+        let sun = at("2020-02-02T12:00:00Z");
+        let next_sun = at("2020-02-09T00:00:00Z");
+        assert_eq!(sun.count_weekday_between(&next_sun, Day::Sunday), 1);
+        assert_eq!(sun.count_weekday_between(&next_sun, Day::Tuesday), 1);
+        assert_eq!(sun.count_weekday_between(&sun, Day::Sunday), 0);
+        assert_eq!(next_sun.count_weekday_between(&sun, Day::Monday), -1);
+        let epoch = at("1970-01-01T00:00:00Z");
+        let y2k = at("2000-01-01T00:00:00Z");
+        for &day in &[Day::Thursday, Day::Friday, Day::Saturday, Day::Sunday] {
+            let brute = (0..y2k.days_since_epoch())
+                .filter(|&d| {
+                    index_from_day(super::weekday_from_epoch_day(d)) == index_from_day(day)
+                })
+                .count() as i64;
+            assert_eq!(epoch.count_weekday_between(&y2k, day), brute);
+        }
+    }
+
+    #[test]
+    fn sortable() {
+        let key = |s| at(s).sortable_key();
+        assert_eq!(key("2020-02-02T02:25:40Z"), "20200202T022540");
+        assert_eq!(key("2020-02-02T02:25:40.123Z"), "20200202T022540.123000000");
+        let mut keys = vec![
+            key("2020-02-02T02:25:41Z"),
+            key("2020-02-02T02:25:40.5Z"),
+            key("1970-01-01T00:00:00Z"),
+            key("2020-02-02T02:25:40Z"),
+            key("2020-02-02T02:25:40.000000001Z"),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "19700101T000000",
+                "20200202T022540",
+                "20200202T022540.000000001",
+                "20200202T022540.500000000",
+                "20200202T022541",
+            ]
+        );
+    }
+
+    #[test]
+    fn separate_date_time() {
+        let pet = PostEpochTime::from_date_time_strings("2020-02-02", "02:25:40").unwrap();
+        assert_eq!(pet.seconds_since_epoch(), 1580610340);
+        let err = PostEpochTime::from_date_time_strings("2020-02-02", "02:25")
+            .err()
+            .unwrap();
+        assert_eq!((err.position(), err.reason()), (5, "expected ':'"));
+        let err = PostEpochTime::from_date_time_strings("2020-02-30", "02:25:40")
+            .err()
+            .unwrap();
+        assert_eq!((err.position(), err.reason()), (8, "day out of range"));
+        assert!(PostEpochTime::from_date_time_strings("1969-12-31", "23:59:59").is_err());
+        assert!(PostEpochTime::from_date_time_strings("2020-02-02", "02:25:40Z").is_err());
+    }
+
+    #[test]
+    fn from_month_end() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.days_from_month_end(), 27);
+        let third_last = pet.nth_from_month_end(2).unwrap();
+        assert_eq!(third_last.to_rfc3339_millis(), "2020-02-27T00:00:00.000Z");
+        assert_eq!(third_last.days_from_month_end(), 2);
+        assert_eq!(pet.nth_from_month_end(28).unwrap().day_of_month(), 1);
+        assert!(pet.nth_from_month_end(29).is_none());
+        let late = PostEpochTime::from_seconds_since_epoch(u64::MAX - 10);
+        assert!(late.nth_from_month_end(0).is_none());
+        let first = late.start_of_month();
+        assert_eq!(
+            late.nth_from_month_end(late.days_in_current_month() - 1),
+            Some(first)
+        );
+    }
+
+    #[test]
+    fn decomposed() {
+        for &secs in &[0, 951782400, 1580610340, 4102444799] {
+            let pet = PostEpochTime {
+                delta: Duration::from_secs(secs),
+            };
+            let d = pet.decompose();
+            assert_eq!(d.year(), pet.year());
+            assert_eq!(index_from_month(d.month()), index_from_month(pet.month()));
+            assert_eq!(d.day_of_month(), pet.day_of_month());
+            assert_eq!(d.day_of_year(), pet.day_of_year());
+            assert_eq!(
+                index_from_day(d.day_of_week()),
+                index_from_day(pet.day_of_week())
+            );
+            assert_eq!(
+                (d.hour(), d.minute(), d.second()),
+                (pet.hour(), pet.minute(), pet.second())
+            );
+        }
+    }
+
+    #[test]
+    fn weekday_of() {
+        let index = |y, m, d| weekday_of_date(y, m, d).map(index_from_day);
+        assert_eq!(index(2020, Month::February, 2), Some(0));
+        assert_eq!(index(1970, Month::January, 1), Some(4));
+        assert_eq!(index(1969, Month::July, 20), Some(0));
+        assert_eq!(index(2000, Month::February, 29), Some(2));
+        assert_eq!(index(2021, Month::February, 29), None);
+        assert_eq!(index(2021, Month::March, 0), None);
+        // The calendar repeats every 400 years, and u64::MAX is the same as 2015
+        // modulo 400, so its January 1 is a Thursday
+        assert_eq!(index(u64::MAX, Month::January, 1), Some(4));
+        assert_eq!(index(u64::MAX, Month::December, 31), Some(4));
+    }
+
+    #[test]
+    fn fraction_rounding() {
+        let pet = at("2020-02-02T02:25:40.123999Z");
+        assert_eq!(pet.to_rfc3339_millis(), "2020-02-02T02:25:40.123Z");
+        assert_eq!(
+            pet.to_rfc3339_millis_with(FractionRounding::HalfUp),
+            "2020-02-02T02:25:40.124Z"
+        );
+        let pet = at("2020-02-02T02:25:40.1234999Z");
+        assert_eq!(
+            pet.to_rfc3339_millis_with(FractionRounding::HalfUp),
+            "2020-02-02T02:25:40.123Z"
+        );
+        let pet = at("2019-12-31T23:59:59.9995Z");
+        assert_eq!(
+            pet.to_rfc3339_millis_with(FractionRounding::HalfUp),
+            "2020-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            pet.to_rfc3339_millis_with(FractionRounding::default()),
+            "2019-12-31T23:59:59.999Z"
+        );
+    }
+
+    #[test]
+    fn epoch_days() {
+        assert_eq!(epoch_day_of_date(1970, Month::January, 1), Some(0));
+        assert_eq!(epoch_day_of_date(2020, Month::February, 2), Some(18294));
+        assert_eq!(epoch_day_of_date(2020, Month::February, 30), None);
+        assert_eq!(epoch_day_of_date(1969, Month::December, 31), None);
+    }
+
+    #[test]
+    fn adjacent_months() {
+        let mar = at("2020-03-15T00:00:00Z");
+        assert_eq!(
+            (mar.previous_month_length(), mar.next_month_length()),
+            (29, 30)
+        );
+        let jan = at("2021-01-31T00:00:00Z");
+        assert_eq!(
+            (jan.previous_month_length(), jan.next_month_length()),
+            (31, 28)
+        );
+        let dec = at("2023-12-01T00:00:00Z");
+        assert_eq!(
+            (dec.previous_month_length(), dec.next_month_length()),
+            (30, 31)
+        );
+        let epoch = at("1970-01-01T00:00:00Z");
+        assert_eq!(epoch.previous_month_length(), 31);
+    }
+
+    #[test]
+    fn expiry() {
+        let past = at("2020-02-02T02:25:40Z");
+        assert!(past.is_expired(Duration::from_secs(60)).unwrap());
+        assert!(!past.is_expired(Duration::MAX).unwrap());
+        assert!(!PostEpochTime::MAX.is_expired(Duration::ZERO).unwrap());
+    }
+
+    #[test]
+    fn buckets() {
+        let pet = at("2020-02-02T02:25:40.5Z");
+        let trunc = |b| pet.truncate_to_seconds(b).to_rfc3339_millis();
+        assert_eq!(trunc(300), "2020-02-02T02:25:00.000Z");
+        assert_eq!(trunc(15), "2020-02-02T02:25:30.000Z");
+        assert_eq!(trunc(1), "2020-02-02T02:25:40.000Z");
+        assert_eq!(trunc(0), "2020-02-02T02:25:40.500Z");
+        assert_eq!(trunc(86400), "2020-02-02T00:00:00.000Z");
+    }
+
+    #[test]
+    fn month_starts() {
+        let starts: Vec<String> = at("2019-11-15T12:00:00Z")
+            .month_starts_until(&at("2020-03-01T00:00:00Z"))
+            .map(|t| t.to_iso_ordinal())
+            .collect();
+        assert_eq!(starts, ["2019-305", "2019-335", "2020-001", "2020-032"]);
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.month_starts_until(&pet).count(), 1);
+        assert_eq!(
+            pet.month_starts_until(&at("2020-02-01T00:00:00Z")).count(),
+            0
+        );
+        // The iterator ends after the last month that can be represented
+        let max = PostEpochTime::MAX;
+        let early = max
+            .checked_sub(Duration::from_secs(100 * seconds_in_day()))
+            .unwrap();
+        let starts: Vec<PostEpochTime> = early.month_starts_until(&max).collect();
+        assert_eq!(starts.len(), 4);
+        assert_eq!(starts.last(), Some(&max.start_of_month()));
+        assert!(starts.iter().all(|t| t.year() == PostEpochTime::MAX_YEAR));
+        assert!(starts.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn from_components_roundtrip() {
+        let pet = PostEpochTime::from_components(2024, Month::February, 29, 23, 59, 58).unwrap();
+        assert_eq!(pet.year(), 2024);
+        assert!(matches!(pet.month(), Month::February));
+        assert_eq!(pet.day_of_month(), 29);
+        assert_eq!((pet.hour(), pet.minute(), pet.second()), (23, 59, 58));
+
+        let err = |y, m, d, h, mi, s| match PostEpochTime::from_components(y, m, d, h, mi, s) {
+            Err(DateTimeError::OutOfRange(field)) => field,
+            _ => panic!("expected an out of range error"),
+        };
+        assert_eq!(err(1969, Month::December, 31, 0, 0, 0), "year");
+        assert_eq!(err(2023, Month::February, 29, 0, 0, 0), "day");
+        assert_eq!(err(2023, Month::April, 0, 0, 0, 0), "day");
+        assert_eq!(err(2023, Month::April, 1, 24, 0, 0), "hour");
+        assert_eq!(err(2023, Month::April, 1, 0, 60, 0), "minute");
+        assert_eq!(err(2023, Month::April, 1, 0, 0, 60), "second");
+    }
+
+    #[test]
+    fn from_epoch_counts() {
+        let pet = PostEpochTime::from_seconds_since_epoch(1580610340);
+        assert_eq!(pet.to_string(), "Sun, 2 Feb 2020 02:25:40");
+        let pet = PostEpochTime::from_millis_since_epoch(1580610340123);
+        assert_eq!(pet.milliseconds_since_epoch(), 1580610340123);
+        assert_eq!(pet.to_rfc3339_millis(), "2020-02-02T02:25:40.123Z");
+        let max = PostEpochTime::from_millis_since_epoch(u128::from(u64::MAX) * 1000 + 999);
+        assert_eq!(max.seconds_since_epoch(), u64::MAX);
+    }
+
+    #[test]
+    fn rfc3339_format() {
+        let pet = PostEpochTime::from_millis_since_epoch(1580610340123);
+        assert_eq!(pet.to_rfc3339(), "2020-02-02T02:25:40Z");
+        assert_eq!(pet.to_iso8601_millis(), "2020-02-02T02:25:40.123Z");
+        assert_eq!(
+            PostEpochTime::from_seconds_since_epoch(0).to_rfc3339(),
+            "1970-01-01T00:00:00Z"
+        );
+        let parsed = PostEpochTime::parse_rfc3339(&pet.to_rfc3339()).unwrap();
+        assert_eq!(parsed.seconds_since_epoch(), pet.seconds_since_epoch());
+        // Sub-millisecond parts are dropped
+        let pet = at("2020-02-02T02:25:40.000000123Z");
+        assert_eq!(pet.to_rfc3339(), "2020-02-02T02:25:40Z");
+        assert_eq!(pet.to_iso8601_millis(), "2020-02-02T02:25:40.000Z");
+    }
+
+    #[test]
+    fn parse_error_kinds() {
+        let kind = |s| PostEpochTime::parse_rfc3339(s).err().unwrap().kind();
+        assert_eq!(kind("2020-02-02 02:25:40Z"), ParseErrorKind::Malformed);
+        assert_eq!(kind("2020-02-02T02:25:40Zjunk"), ParseErrorKind::Malformed);
+        assert_eq!(kind("2020-0x-02T02:25:40Z"), ParseErrorKind::NonNumeric);
+        assert_eq!(kind("2020-02-02T02:25:40.Z"), ParseErrorKind::NonNumeric);
+        assert_eq!(kind("2020-13-02T02:25:40Z"), ParseErrorKind::OutOfRange);
+        assert_eq!(kind("1969-12-31T23:59:59Z"), ParseErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn enum_ordering() {
+        assert!(Day::Sunday < Day::Monday && Day::Friday < Day::Saturday);
+        assert!(Month::January < Month::February && Month::November < Month::December);
+        let mut months = std::collections::BTreeMap::new();
+        months.insert(Month::March, 3);
+        months.insert(Month::January, 1);
+        assert_eq!(months.keys().next(), Some(&Month::January));
+        assert_eq!(Day::Saturday.next(), Day::Sunday);
+    }
+
+    #[test]
+    fn day_indices() {
+        for index in 0..7 {
+            assert_eq!(index_from_day(day_from_index(index).unwrap()), index);
+        }
+        assert_eq!(day_from_index(0), Some(Day::Sunday));
+        assert_eq!(day_from_index(6), Some(Day::Saturday));
+        assert_eq!(day_from_index(7), None);
+        assert_eq!(day_from_index(u64::MAX), None);
+    }
+
+    #[test]
+    fn calendar_arithmetic() {
+        let pet = at("2020-01-31T02:25:40.5Z");
+        let fmt = |p: PostEpochTime| p.to_rfc3339_millis();
+        assert_eq!(fmt(pet.add_months(1)), "2020-02-29T02:25:40.500Z");
+        assert_eq!(fmt(pet.add_months(13)), "2021-02-28T02:25:40.500Z");
+        assert_eq!(fmt(pet.add_months(-2)), "2019-11-30T02:25:40.500Z");
+        assert_eq!(fmt(pet.add_months(0)), "2020-01-31T02:25:40.500Z");
+        assert_eq!(
+            fmt(at("1970-03-31T00:00:00Z").add_months(-2)),
+            "1970-01-31T00:00:00.000Z"
+        );
+        assert_eq!(fmt(pet.add_days(30)), "2020-03-01T02:25:40.500Z");
+        assert_eq!(fmt(pet.add_days(-31)), "2019-12-31T02:25:40.500Z");
+        let moved = pet.add_months(5);
+        assert_eq!(
+            (moved.year(), moved.month(), moved.day_of_month()),
+            (2020, Month::June, 30)
+        );
+        let leap = at("2020-02-29T12:00:00Z");
+        assert_eq!(fmt(leap.add_years(1)), "2021-02-28T12:00:00.000Z");
+        assert_eq!(fmt(leap.add_years(4)), "2024-02-29T12:00:00.000Z");
+        assert_eq!(fmt(leap.add_years(-1)), "2019-02-28T12:00:00.000Z");
+        assert_eq!(fmt(pet.add_years(-50)), "1970-01-31T02:25:40.500Z");
+    }
+
+    #[test]
+    fn differences() {
+        let a = at("2020-02-02T23:00:00Z");
+        let b = at("2020-02-03T01:00:00.5Z");
+        assert_eq!(b.duration_since(&a), Duration::from_millis(7_200_500));
+        assert_eq!(b.signed_seconds_since(&a), 7200);
+        assert_eq!(a.signed_seconds_since(&b), -7200);
+        assert_eq!(a.days_between(&b), 1);
+        assert_eq!(b.days_between(&a), 1);
+        assert_eq!(PostEpochTime::MAX.signed_seconds_since(&a), i64::MAX);
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2020));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2019));
+    }
+
+    #[test]
+    fn twelve_hour() {
+        let clock = |p: PostEpochTime| format!("{} {}", p.hour_12(), p.meridiem());
+        assert_eq!(clock(at("2020-02-02T00:00:00Z")), "12 AM");
+        assert_eq!(clock(at("2020-02-02T11:59:59Z")), "11 AM");
+        assert_eq!(clock(at("2020-02-02T12:00:00Z")), "12 PM");
+        assert_eq!(clock(at("2020-02-02T13:00:00Z")), "1 PM");
+        assert_eq!(clock(at("2020-02-02T23:00:00Z")), "11 PM");
+    }
+
+    #[test]
+    fn ordinals() {
+        let suffixes: Vec<&str> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 31, 111]
+            .iter()
+            .map(|&d| ordinal_suffix(d))
+            .collect();
+        assert_eq!(
+            suffixes,
+            ["st", "nd", "rd", "th", "th", "th", "th", "st", "nd", "rd", "st", "th"]
+        );
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.day_of_month_ordinal(), "2nd");
+    }
+
+    #[test]
+    fn strftime() {
+        let pet = at("2020-02-02T02:25:40Z");
+        assert_eq!(pet.format("%a, %d %b %Y %H:%M:%S"), pet.to_string_padded());
+        assert_eq!(pet.format("%A %B %Y-%m-%d"), "Sunday February 2020-02-02");
+        assert_eq!(pet.format("100%% at %H%M, %q%"), "100% at 0225, %q%");
+        assert_eq!(pet.format(""), "");
+    }
+
+    #[test]
+    fn quarters_of_year() {
+        let quarter = |s| PostEpochTime::parse_iso_ordinal(s).unwrap().quarter();
+        assert_eq!(quarter("2020-001"), 1); // January 1
+        assert_eq!(quarter("2020-091"), 1); // March 31
+        assert_eq!(quarter("2020-092"), 2); // April 1
+        assert_eq!(quarter("2020-182"), 2); // June 30
+        assert_eq!(quarter("2020-183"), 3); // July 1
+        assert_eq!(quarter("2020-274"), 3); // September 30
+        assert_eq!(quarter("2020-275"), 4); // October 1
+        assert_eq!(quarter("2020-366"), 4); // December 31
+    }
+
+    #[test]
+    fn iso_week_numbers() {
+        let week = |s| {
+            let pet = at(s);
+            (pet.iso_week_year(), pet.iso_week())
+        };
+        assert_eq!(week("2021-01-01T00:00:00Z"), (2020, 53));
+        assert_eq!(week("2020-12-31T00:00:00Z"), (2020, 53));
+        assert_eq!(week("2021-01-04T00:00:00Z"), (2021, 1));
+        assert_eq!(week("2019-12-30T00:00:00Z"), (2020, 1));
+        assert_eq!(week("1970-01-01T00:00:00Z"), (1970, 1));
+        assert_eq!(week("2020-02-02T00:00:00Z"), (2020, 5));
+    }
+
+    #[test]
+    fn pre_epoch() {
+        let last = EpochTime::from_seconds_since_epoch(-1);
+        assert_eq!(last.to_string(), "Wed, 31 Dec 1969 23:59:59");
+        assert_eq!(
+            (last.year(), last.day_of_year(), last.days_since_epoch()),
+            (1969, 365, -1)
+        );
+        assert!(last.to_post_epoch().is_none());
+
+        let st = SystemTime::UNIX_EPOCH - Duration::from_millis(1500);
+        let et = EpochTime::from(&st).unwrap();
+        assert_eq!(
+            (et.seconds_since_epoch(), et.subsec_nanos()),
+            (-2, 500_000_000)
+        );
+        assert_eq!(et.to_string(), "Wed, 31 Dec 1969 23:59:58");
+
+        let moon = EpochTime::from_components(1969, Month::July, 20, 20, 17, 40).unwrap();
+        assert_eq!(moon.seconds_since_epoch(), -14182940);
+        assert_eq!(moon.day_of_week(), Day::Sunday);
+        let leap = EpochTime::from_components(1600, Month::February, 29, 0, 0, 0).unwrap();
+        assert_eq!(leap.to_string(), "Tue, 29 Feb 1600 00:00:00");
+        assert!(EpochTime::from_components(1900, Month::February, 29, 0, 0, 0).is_err());
+        let bce = EpochTime::from_components(-44, Month::March, 15, 12, 0, 0).unwrap();
+        assert_eq!(
+            (bce.year(), bce.month(), bce.day_of_month(), bce.hour()),
+            (-44, Month::March, 15, 12)
+        );
+
+        for &secs in &[0, 1, 951782400, 1580610340, 4102444799] {
+            let pet = PostEpochTime::from_seconds_since_epoch(secs);
+            let et = EpochTime::from_seconds_since_epoch(secs as i64);
+            assert_eq!(et.to_string(), pet.to_string());
+            assert_eq!(et.to_post_epoch().unwrap().seconds_since_epoch(), secs);
+        }
+        let min = EpochTime::from_seconds_since_epoch(i64::MIN);
+        let max = EpochTime::from_seconds_since_epoch(i64::MAX);
+        assert!(min.year() < -292_000_000_000 && max.year() > 292_000_000_000);
+    }
+
+    #[test]
+    fn duration_arithmetic() {
+        let pet = at("2019-12-31T23:59:59Z") + Duration::from_secs(1);
+        assert_eq!(pet.to_rfc3339(), "2020-01-01T00:00:00Z");
+        let pet = pet - Duration::from_secs(86400 * 31 + 1);
+        assert_eq!(pet.to_rfc3339(), "2019-11-30T23:59:59Z");
+        let epoch = at("1970-01-01T00:00:10Z");
+        assert!(epoch.checked_sub(Duration::from_secs(11)).is_none());
+        assert_eq!(
+            epoch
+                .checked_sub(Duration::from_secs(10))
+                .unwrap()
+                .seconds_since_epoch(),
+            0
+        );
+        assert_eq!((epoch - Duration::from_secs(11)).seconds_since_epoch(), 0);
+    }
+
+    #[test]
+    fn start_of_units() {
+        let pet = at("2020-02-29T23:59:58.75Z");
+        let day = pet.start_of_day();
+        assert_eq!(day.second_in_day(), 0);
+        assert_eq!(
+            (day.year(), day.month(), day.day_of_month()),
+            (2020, Month::February, 29)
+        );
+        assert_eq!(
+            pet.start_of_hour().to_rfc3339_millis(),
+            "2020-02-29T23:00:00.000Z"
+        );
+        assert_eq!(
+            pet.start_of_minute().to_rfc3339_millis(),
+            "2020-02-29T23:59:00.000Z"
+        );
+    }
+
+    #[test]
+    fn sorting() {
+        let mut times: Vec<PostEpochTime> = [1580610340, 0, 951782400, 1580610339]
+            .iter()
+            .map(|&s| PostEpochTime::from_seconds_since_epoch(s))
+            .collect();
+        times.sort();
+        let secs: Vec<u64> = times.iter().map(|t| t.seconds_since_epoch()).collect();
+        assert_eq!(secs, [0, 951782400, 1580610339, 1580610340]);
+        assert_eq!(times.iter().max(), times.last());
+        let set: std::collections::BTreeSet<PostEpochTime> = times.iter().copied().collect();
+        assert_eq!(set.len(), 4);
+        assert!(times[0] == PostEpochTime::from_seconds_since_epoch(0));
+    }
+
+    #[test]
+    fn system_time_roundtrip() {
+        let st = SystemTime::UNIX_EPOCH + Duration::new(1580610340, 123);
+        let pet = PostEpochTime::from(&st).unwrap();
+        assert_eq!(pet.to_system_time(), st);
+        assert_eq!(
+            pet.as_duration_since_epoch(),
+            Duration::new(1580610340, 123)
+        );
+    }
+
+    #[test]
+    fn names_from_strings() {
+        assert_eq!(month_from_string("January"), Some(Month::January));
+        assert_eq!(month_from_string("sep"), Some(Month::September));
+        assert_eq!(month_from_string("dEcEmBeR"), Some(Month::December));
+        assert_eq!(month_from_string("Janu"), None);
+        assert_eq!(month_from_string(""), None);
+        assert_eq!(day_from_string("Monday"), Some(Day::Monday));
+        assert_eq!(day_from_string("THU"), Some(Day::Thursday));
+        assert_eq!(day_from_string("sunday "), None);
+        assert_eq!(day_from_string("Mo"), None);
+    }
+
+    #[test]
+    fn weekends() {
+        // 2020-02-02 is a Sunday
+        let sunday = at("2020-02-02T12:00:00Z");
+        let weekend: Vec<bool> = (0..7).map(|d| sunday.add_days(d).is_weekend()).collect();
+        assert_eq!(weekend, [true, false, false, false, false, false, true]);
+        for d in 0..7 {
+            let pet = sunday.add_days(d);
+            assert_eq!(pet.is_weekday(), !pet.is_weekend());
+            assert_eq!(pet.day_of_week().is_weekend(), pet.is_weekend());
+        }
+    }
+
+    #[test]
+    fn enum_navigation() {
+        assert_eq!(Day::Saturday.next(), Day::Sunday);
+        assert_eq!(Day::Sunday.previous(), Day::Saturday);
+        assert_eq!(Day::Wednesday.next(), Day::Thursday);
+        assert_eq!(Day::Wednesday.previous(), Day::Tuesday);
+        assert_eq!(Month::December.next(), Month::January);
+        assert_eq!(Month::January.previous(), Month::December);
+        assert_eq!(Month::June.next(), Month::July);
+        assert_eq!(Month::June.previous(), Month::May);
+        for index in 1..=12 {
+            let month = month_from_index(index).unwrap();
+            assert_eq!(month.next().previous(), month);
+        }
+    }
+
+    #[test]
+    fn offset_seconds() {
+        let ist = at("2020-02-02T20:00:00Z")
+            .with_offset_seconds(19800)
+            .unwrap();
+        assert_eq!(ist.to_string(), "Mon, 3 Feb 2020 01:30:00 +05:30");
+        assert_eq!(ist.day_of_week(), Day::Monday);
+        let pst = at("2020-02-02T03:00:00Z")
+            .with_offset_seconds(-8 * 3600)
+            .unwrap();
+        assert_eq!(pst.to_string(), "Sat, 1 Feb 2020 19:00:00 -08:00");
+        assert_eq!(pst.to_utc().to_rfc3339(), "2020-02-02T03:00:00Z");
+        assert!(at("1970-01-01T07:59:59Z")
+            .with_offset_seconds(-8 * 3600)
+            .is_none());
+        assert!(at("1970-01-01T08:00:00Z")
+            .with_offset_seconds(-8 * 3600)
+            .is_some());
+        assert!(at("2020-02-02T00:00:00Z")
+            .with_offset_seconds(86400)
+            .is_none());
+        assert!(at("2020-02-02T00:00:00Z")
+            .with_offset_seconds(i64::MAX)
+            .is_none());
+    }
+
+    #[test]
+    fn day_range() {
+        let start = at("2020-02-27T12:00:00Z");
+        let days: Vec<String> = start
+            .days_until(&at("2020-03-02T00:00:00Z"))
+            .map(|t| t.to_rfc3339())
+            .collect();
+        assert_eq!(
+            days,
+            [
+                "2020-02-27T00:00:00Z",
+                "2020-02-28T00:00:00Z",
+                "2020-02-29T00:00:00Z",
+                "2020-03-01T00:00:00Z"
+            ]
+        );
+        assert_eq!(start.days_until(&at("2020-03-02T00:00:01Z")).count(), 5);
+        assert_eq!(start.days_until(&start).count(), 0);
+        assert_eq!(start.days_until(&at("2020-01-01T00:00:00Z")).count(), 0);
+        assert_eq!(start.days_until(&at("2020-02-27T12:00:01Z")).count(), 1);
+    }
+
+    #[test]
+    fn const_helpers() {
+        const DAY: u64 = seconds_in_day();
+        const LEAP: u64 = days_in_year(2020);
+        const DECEMBER: u64 = index_from_month(Month::December);
+        const BUCKETS: [u8; seconds_in_hour() as usize / seconds_in_minute() as usize] = [0; 60];
+        assert_eq!((DAY, LEAP, DECEMBER, BUCKETS.len()), (86400, 366, 12, 60));
+        const MARCH: Option<Month> = month_from_index(3);
+        assert_eq!(MARCH, Some(Month::March));
+    }
+
+    #[test]
+    fn julian_days() {
+        assert_eq!(at("2000-01-01T12:00:00Z").julian_date(), 2451545.0);
+        assert_eq!(at("2000-01-01T00:00:00Z").julian_date(), 2451544.5);
+        assert_eq!(at("1970-01-01T00:00:00Z").julian_date(), 2440587.5);
+        assert_eq!(at("2000-01-01T23:59:59Z").julian_day_number(), 2451545);
+        assert_eq!(at("1970-01-01T00:00:00Z").julian_day_number(), 2440588);
+        let pet = at("2020-02-29T12:00:00Z");
+        assert_eq!(
+            Some(pet.julian_day_number()),
+            jdn_from_ymd(2020, Month::February, 29)
+        );
+        assert!(PostEpochTime::MAX.julian_day_number() > 0);
+        let pet = at("2020-02-02T02:25:40Z");
+        assert!((pet.julian_date() - 2400000.5 - pet.modified_julian_date()).abs() < 1e-6);
+        assert_eq!(jdn_from_ymd(2000, Month::January, 1), Some(2451545));
+        assert_eq!(jdn_from_ymd(-4713, Month::November, 24), Some(0));
+        assert_eq!(jdn_from_ymd(1858, Month::November, 17), Some(2400001));
+        assert_eq!(jdn_from_ymd(2021, Month::February, 29), None);
+    }
+
+    #[test]
+    fn subsecond_accessors() {
+        let pet = at("2020-02-02T02:25:40.123456789Z");
+        assert_eq!(pet.millisecond(), 123);
+        assert_eq!(pet.microsecond(), 123456);
+        assert_eq!(pet.nanosecond(), 123456789);
+        assert_eq!(pet.to_rfc3339_nanos(), "2020-02-02T02:25:40.123456789Z");
+        let parsed = PostEpochTime::parse_rfc3339(&pet.to_rfc3339_nanos()).unwrap();
+        assert_eq!(parsed, pet);
+        let pet = at("2020-02-02T02:25:40Z") + Duration::from_nanos(123);
+        assert_eq!(
+            (pet.millisecond(), pet.microsecond(), pet.nanosecond()),
+            (0, 0, 123)
+        );
+        assert_eq!(pet.to_rfc3339_nanos(), "2020-02-02T02:25:40.000000123Z");
+    }
+
+    #[test]
+    fn weekday_occurrences() {
+        // In March 2020, Sundays fall on the 1st, 8th, 15th, 22nd and 29th
+        assert_eq!(at("2020-03-01T00:00:00Z").week_of_month(), 1);
+        assert_eq!(at("2020-03-08T00:00:00Z").week_of_month(), 2);
+        assert_eq!(at("2020-03-29T00:00:00Z").week_of_month(), 5);
+        assert!(at("2020-03-29T00:00:00Z").is_last_weekday_of_month());
+        assert!(!at("2020-03-22T00:00:00Z").is_last_weekday_of_month());
+        assert!(at("2020-03-25T00:00:00Z").is_last_weekday_of_month());
+        assert!(!at("2020-03-24T00:00:00Z").is_last_weekday_of_month());
+    }
+
+    #[test]
+    fn month_try_from() {
+        assert_eq!(Month::try_from(2u64), Ok(Month::February));
+        assert_eq!(Month::try_from(12u8), Ok(Month::December));
+        assert_eq!(Month::try_from(0u64), Err(InvalidMonthIndex(0)));
+        assert_eq!(Month::try_from(13u8), Err(InvalidMonthIndex(13)));
+        assert_eq!(u64::from(Month::March), 3);
+        for index in 1..=12u64 {
+            assert_eq!(Month::try_from(index).map(u64::from), Ok(index));
+        }
+    }
+
+    #[test]
+    fn seasons() {
+        let cases = [
+            ("2020-01-15T00:00:00Z", Season::Winter, Season::Summer),
+            ("2020-04-15T00:00:00Z", Season::Spring, Season::Autumn),
+            ("2020-07-15T00:00:00Z", Season::Summer, Season::Winter),
+            ("2020-10-15T00:00:00Z", Season::Autumn, Season::Spring),
+        ];
+        for &(s, northern, southern) in cases.iter() {
+            let time = at(s);
+            assert_eq!(time.season(Hemisphere::Northern), northern);
+            assert_eq!(time.season(Hemisphere::Southern), southern);
+        }
+        assert_eq!(Season::Autumn.to_string(), "Autumn");
+    }
+
+    #[test]
+    fn split_month_boundaries() {
+        assert_eq!(split_month(2019, 0), (Month::January, 0));
+        assert_eq!(split_month(2019, 58), (Month::February, 27));
+        assert_eq!(split_month(2019, 59), (Month::March, 0));
+        assert_eq!(split_month(2020, 59), (Month::February, 28));
+        assert_eq!(split_month(2019, 334), (Month::December, 0));
+        assert_eq!(split_month(2020, 365), (Month::December, 30));
+        // Every epoch day maps to the weekday one after the previous day's
+        for day in 0..14 {
+            assert_eq!(
+                super::weekday_from_epoch_day(day + 1),
+                super::weekday_from_epoch_day(day).next()
+            );
+        }
+        assert_eq!(super::weekday_from_epoch_day(0), Day::Thursday);
+        assert_eq!(super::weekday_from_epoch_day(3), Day::Sunday);
+    }
+
+    #[test]
+    fn day_of_week_for_date() {
+        assert_eq!(
+            super::day_of_week_for_date(2020, Month::February, 29),
+            Day::Saturday
+        );
+        assert_eq!(
+            super::day_of_week_for_date(1970, Month::January, 1),
+            Day::Thursday
+        );
+        assert_eq!(
+            super::day_of_week_for_date(2000, Month::January, 1),
+            Day::Saturday
+        );
+        assert_eq!(
+            super::day_of_week_for_date(1969, Month::July, 20),
+            Day::Sunday
+        );
+        assert_eq!(
+            super::day_of_week_for_date(2021, Month::March, 0),
+            Day::Sunday
+        );
+        for secs in (0..2_000_000_000).step_by(86_400 * 37 + 1234) {
+            let pet = PostEpochTime::from_seconds_since_epoch(secs);
+            assert_eq!(
+                super::day_of_week_for_date(pet.year(), pet.month(), pet.day_of_month()),
+                pet.day_of_week(),
+                "{}",
+                secs
+            );
+        }
+    }
+
+    #[test]
+    fn from_days_since_epoch() {
+        for &days in [0, 1, 18321, 18_500, 1_000_000].iter() {
+            assert_eq!(
+                PostEpochTime::from_days_since_epoch(days).days_since_epoch(),
+                days
+            );
+        }
+        let pet = PostEpochTime::from_days_since_epoch(18321);
+        assert_eq!(pet.to_rfc3339(), "2020-02-29T00:00:00Z");
+        assert_eq!(
+            PostEpochTime::from_days_since_epoch(0).seconds_since_epoch(),
+            0
+        );
+    }
+
+    #[test]
+    fn localized_names() {
+        assert_eq!(
+            month_string_locale(Month::February, Locale::French),
+            "février"
+        );
+        assert_eq!(month_string_locale(Month::August, Locale::French), "août");
+        assert_eq!(month_string_locale(Month::March, Locale::German), "März");
+        assert_eq!(
+            month_string_locale(Month::December, Locale::German),
+            "Dezember"
+        );
+        assert_eq!(month_string_locale(Month::May, Locale::Spanish), "mayo");
+        assert_eq!(day_string_locale(Day::Monday, Locale::French), "lundi");
+        assert_eq!(day_string_locale(Day::Sunday, Locale::German), "Sonntag");
+        assert_eq!(day_string_locale(Day::Saturday, Locale::Spanish), "sábado");
+        for index in 1..=12 {
+            let month = month_from_index(index).unwrap();
+            assert_eq!(
+                month_string_locale(month, Locale::English),
+                month_string(month)
+            );
+        }
+    }
+
+    #[test]
+    fn from_saturating() {
+        let pre_epoch = SystemTime::UNIX_EPOCH - Duration::from_millis(3);
+        assert!(PostEpochTime::from(&pre_epoch).is_err());
+        let pet = PostEpochTime::from_saturating(&pre_epoch);
+        assert_eq!(pet.as_duration_since_epoch(), Duration::ZERO);
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::new(1580610340, 123);
+        assert_eq!(
+            PostEpochTime::from_saturating(&timestamp),
+            PostEpochTime::from(&timestamp).unwrap()
+        );
+    }
+
+    #[test]
+    fn days_remaining() {
+        let nye = at("2020-12-31T23:59:59Z");
+        assert_eq!(nye.days_remaining_in_year(), 0);
+        assert_eq!(nye.days_remaining_in_month(), 0);
+        assert_eq!(at("2019-01-01T00:00:00Z").days_remaining_in_year(), 364);
+        assert_eq!(at("2020-01-01T00:00:00Z").days_remaining_in_year(), 365);
+        let feb = at("2020-02-10T00:00:00Z");
+        assert_eq!(feb.days_remaining_in_month(), 19);
+        assert_eq!(at("2019-02-10T00:00:00Z").days_remaining_in_month(), 18);
+        assert_eq!(at("2020-02-29T00:00:00Z").days_remaining_in_month(), 0);
+        assert_eq!(at("2020-02-29T00:00:00Z").days_remaining_in_year(), 306);
+    }
+
+    #[test]
+    fn split_year_and_month_sweep() {
+        // Walk forward one day at a time through more than two 400 year cycles,
+        // and check the arithmetic splits against the walked calendar.
+        let (mut year, mut day_of_year) = (PostEpochTime::MIN_YEAR, 0);
+        let (mut month, mut day_of_month) = (Month::January, 0);
+        for days in 0..(2 * DAYS_IN_400_YEARS + 1000) {
+            assert_eq!(split_year(days), (year, day_of_year), "{}", days);
+            assert_eq!(
+                split_month(year, day_of_year),
+                (month, day_of_month),
+                "{}",
+                days
+            );
+            day_of_month += 1;
+            day_of_year += 1;
+            if day_of_month == days_in_month(year, month) {
+                day_of_month = 0;
+                month = month.next();
+                if month == Month::January {
+                    year += 1;
+                    day_of_year = 0;
+                }
+            }
+        }
+        assert_eq!(split_year(days_before_year(1_000_000)), (1_000_000, 0));
+        assert_eq!(
+            split_year(days_before_year(1_000_001) - 1),
+            (1_000_000, days_in_year(1_000_000) - 1)
+        );
+        let last = PostEpochTime::MAX;
+        let d = last.decompose();
+        assert_eq!(
+            epoch_day(d.year(), d.month(), d.day_of_month()),
+            last.days_since_epoch()
+        );
+    }
+
+    #[test]
+    fn hash_by_day() {
+        use std::collections::HashMap;
+
+        let mut buckets: HashMap<PostEpochTime, Vec<u64>> = HashMap::new();
+        for &secs in [1580610340, 1580601600, 1580687999, 1580688000, 1580700000].iter() {
+            let pet = PostEpochTime::from_seconds_since_epoch(secs);
+            buckets.entry(pet.start_of_day()).or_default().push(secs);
+        }
+        assert_eq!(buckets.len(), 2);
+        let feb2 = at("2020-02-02T00:00:00Z");
+        assert_eq!(buckets[&feb2], vec![1580610340, 1580601600, 1580687999]);
+        assert_eq!(buckets[&feb2.tomorrow()].len(), 2);
+    }
+
+    #[test]
+    fn year_boundaries() {
+        let fields = |p: PostEpochTime| (p.year(), p.month(), p.day_of_month(), p.day_of_year());
+        // The last second of a leap year and the first second of the next
+        let last = at("2020-12-31T23:59:59Z");
+        assert_eq!(fields(last), (2020, Month::December, 31, 366));
+        let first = PostEpochTime::from_seconds_since_epoch(last.seconds_since_epoch() + 1);
+        assert_eq!(first.to_rfc3339(), "2021-01-01T00:00:00Z");
+        assert_eq!(fields(first), (2021, Month::January, 1, 1));
+        assert_eq!(
+            fields(at("2019-12-31T23:59:59Z")),
+            (2019, Month::December, 31, 365)
+        );
+        assert_eq!(
+            fields(at("2000-12-31T12:00:00Z")),
+            (2000, Month::December, 31, 366)
+        );
+        assert_eq!(
+            fields(at("2100-12-31T12:00:00Z")),
+            (2100, Month::December, 31, 365)
+        );
+        assert_eq!(
+            fields(at("1970-01-01T00:00:00Z")),
+            (1970, Month::January, 1, 1)
+        );
+        // Across the leap day in 2020
+        assert_eq!(
+            fields(at("2020-02-28T23:59:59Z")),
+            (2020, Month::February, 28, 59)
+        );
+        assert_eq!(
+            fields(at("2020-02-29T00:00:00Z")),
+            (2020, Month::February, 29, 60)
+        );
+        assert_eq!(
+            fields(at("2020-02-29T23:59:59Z")),
+            (2020, Month::February, 29, 60)
+        );
+        assert_eq!(
+            fields(at("2020-03-01T00:00:00Z")),
+            (2020, Month::March, 1, 61)
+        );
+        assert_eq!(
+            fields(at("2019-03-01T00:00:00Z")),
+            (2019, Month::March, 1, 60)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "next_midnight result out of range")]
+    fn next_midnight_overflow() {
+        PostEpochTime::MAX.next_midnight();
+    }
+
+    #[test]
+    fn decompose_matches_accessors() {
+        let spread = (0..u64::MAX / 2).step_by(usize::MAX / 7 + 12_345);
+        let samples = [0, 68_169_599, 951_868_800, 1_580_610_340, 253_402_300_799];
+        for secs in samples.iter().copied().chain(spread).chain(Some(u64::MAX)) {
+            let pet = PostEpochTime::from_seconds_since_epoch(secs);
+            let d = pet.decompose();
+            assert_eq!(
+                (d.year(), d.month(), d.day_of_month(), d.day_of_year()),
+                (
+                    pet.year(),
+                    pet.month(),
+                    pet.day_of_month(),
+                    pet.day_of_year()
+                ),
+                "{}",
+                secs
+            );
+            assert_eq!(d.day_of_week(), pet.day_of_week(), "{}", secs);
+            assert_eq!(
+                (d.hour(), d.minute(), d.second()),
+                (pet.hour(), pet.minute(), pet.second()),
+                "{}",
+                secs
+            );
+            let expected = format!(
+                "{} {} {}",
+                pet.day_of_month(),
+                month_abbrev_string(pet.month()),
+                pet.year()
+            );
+            assert_eq!(pet.date_only().to_string(), expected, "{}", secs);
+        }
+        for &secs in [-1, 0, 951_868_800, -12_219_292_800].iter() {
+            let et = EpochTime::from_seconds_since_epoch(secs);
+            let expected = format!(
+                "{}, {} {} {} {:02}:{:02}:{:02}",
+                day_abbrev_string(et.day_of_week()),
+                et.day_of_month(),
+                month_abbrev_string(et.month()),
+                et.year(),
+                et.hour(),
+                et.minute(),
+                et.second()
+            );
+            assert_eq!(et.to_string(), expected, "{}", secs);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "add_days result out of range")]
+    fn add_days_overflow() {
+        at("2020-02-02T02:25:40Z").add_days(213_503_982_334_602);
     }
 }
@@ -0,0 +1,19 @@
+// Exercises the crate from a no_std crate. CI runs this with
+// --no-default-features, so that the library is built without std as well.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use datetimeutils::{days_in_month, Day, Month, PostEpochTime};
+
+#[test]
+fn core_api() {
+    assert_eq!(days_in_month(2020, Month::February), 29);
+    assert_eq!(days_in_month(2019, Month::February), 28);
+    assert_eq!(Month::December.next(), Month::January);
+    let pet = PostEpochTime::from_seconds_since_epoch(1580610340);
+    assert_eq!(pet.day_of_week(), Day::Sunday);
+    assert_eq!(pet.month(), Month::February);
+    assert_eq!(pet.to_string(), "Sun, 2 Feb 2020 02:25:40");
+}